@@ -0,0 +1,125 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::tmerc::wrap_lon;
+use crate::{vdist, vreckon, Ellipsoid};
+
+const STEP: f64 = 1e-5;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AzimuthalEquidistant {
+    pub lat0: f64,
+    pub lon0: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+    pub ellipsoid: Ellipsoid,
+}
+
+impl AzimuthalEquidistant {
+    pub fn new(
+        lat0: f64,
+        lon0: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let lat0 = if deg { lat0.to_radians() } else { lat0 };
+        let lon0 = if deg { lon0.to_radians() } else { lon0 };
+
+        if lat0.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        Some(AzimuthalEquidistant {
+            lat0,
+            lon0,
+            false_easting,
+            false_northing,
+            ellipsoid: ell.clone(),
+        })
+    }
+
+    pub fn distance_azimuth(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        if lat.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        let (s, az) = vdist(self.lat0, self.lon0, lat, lon, &self.ellipsoid, false)?;
+
+        if !s.is_finite() || !az.is_finite() {
+            return None;
+        }
+
+        Some((s, if deg { az.to_degrees() } else { az }))
+    }
+
+    pub fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        let (s, az) = self.distance_azimuth(lat, lon, deg)?;
+        let az = if deg { az.to_radians() } else { az };
+
+        Some((
+            self.false_easting + s * az.sin(),
+            self.false_northing + s * az.cos(),
+        ))
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)> {
+        let dx = x - self.false_easting;
+        let dy = y - self.false_northing;
+
+        let s = dx.hypot(dy);
+        let az = dx.atan2(dy);
+
+        let (lat, lon) = if s == 0. {
+            (self.lat0, self.lon0)
+        } else {
+            let (lat, lon) = vreckon(self.lat0, self.lon0, s, az, &self.ellipsoid, false)?;
+            (lat, wrap_lon(lon))
+        };
+
+        let lat = if deg { lat.to_degrees() } else { lat };
+        let lon = if deg { lon.to_degrees() } else { lon };
+
+        Some((lat, lon))
+    }
+
+    pub fn azimuth(&self, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+        Some(self.distance_azimuth(lat, lon, deg)?.1)
+    }
+
+    pub fn scale(&self, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+        let (s, az) = self.distance_azimuth(lat, lon, deg)?;
+        let az = if deg { az.to_radians() } else { az };
+
+        if s < 1. {
+            return Some(1.);
+        }
+
+        let ell = &self.ellipsoid;
+        let (lat1, lon1) = vreckon(self.lat0, self.lon0, s, az - STEP, ell, false)?;
+        let (lat2, lon2) = vreckon(self.lat0, self.lon0, s, az + STEP, ell, false)?;
+
+        let m12 = vdist(lat1, lon1, lat2, lon2, ell, false)?.0 / (2. * STEP);
+
+        Some(s / m12)
+    }
+
+    pub fn convergence(&self, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        if lat.abs() > FRAC_PI_2 - STEP {
+            return None;
+        }
+
+        let (x1, y1) = self.forward(lat - STEP, lon, false)?;
+        let (x2, y2) = self.forward(lat + STEP, lon, false)?;
+
+        let gamma = -(x2 - x1).atan2(y2 - y1);
+
+        Some(if deg { gamma.to_degrees() } else { gamma })
+    }
+}
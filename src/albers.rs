@@ -0,0 +1,144 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::rcurve::parallel_radius;
+use crate::rsphere::authalic;
+use crate::tmerc::wrap_lon;
+use crate::{authalic2geodetic, geodetic2authalic, Ellipsoid};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlbersEqualArea {
+    pub lat0: f64,
+    pub lon0: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+    pub ellipsoid: Ellipsoid,
+    qp: f64,
+    n: f64,
+    c: f64,
+    rho0: f64,
+}
+
+impl AlbersEqualArea {
+    pub fn new(
+        lat1: f64,
+        lat2: f64,
+        lat0: f64,
+        lon0: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let lat1 = if deg { lat1.to_radians() } else { lat1 };
+        let lat2 = if deg { lat2.to_radians() } else { lat2 };
+        let lat0 = if deg { lat0.to_radians() } else { lat0 };
+        let lon0 = if deg { lon0.to_radians() } else { lon0 };
+
+        if lat1.abs() >= FRAC_PI_2 || lat2.abs() >= FRAC_PI_2 || lat0.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        let qp = 2. * (authalic(ell) / ell.semimajor_axis).powi(2);
+
+        let m1 = parallel_radius(lat1, ell);
+        let m2 = parallel_radius(lat2, ell);
+        let q1 = qp * geodetic2authalic(lat1, ell, false)?.sin();
+        let q2 = qp * geodetic2authalic(lat2, ell, false)?.sin();
+
+        let n = if (lat1 - lat2).abs() < 1e-12 {
+            lat1.sin()
+        } else {
+            (m1.powi(2) - m2.powi(2)) / (q2 - q1)
+        };
+
+        if n == 0. || !n.is_finite() {
+            return None;
+        }
+
+        let mut albers = AlbersEqualArea {
+            lat0,
+            lon0,
+            false_easting,
+            false_northing,
+            ellipsoid: ell.clone(),
+            qp,
+            n,
+            c: m1.powi(2) + n * q1,
+            rho0: 0.,
+        };
+
+        albers.rho0 = albers.rho(lat0)?;
+
+        Some(albers)
+    }
+
+    fn rho(&self, lat: f64) -> Option<f64> {
+        let q = self.qp * geodetic2authalic(lat, &self.ellipsoid, false)?.sin();
+        let rho2 = self.c - self.n * q;
+
+        if rho2 < 0. {
+            return None;
+        }
+
+        Some(self.ellipsoid.semimajor_axis * rho2.sqrt() / self.n)
+    }
+
+    pub fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        let rho = self.rho(lat)?;
+        let theta = self.n * wrap_lon(lon - self.lon0);
+
+        Some((
+            self.false_easting + rho * theta.sin(),
+            self.false_northing + self.rho0 - rho * theta.cos(),
+        ))
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)> {
+        let dx = (x - self.false_easting) * self.n.signum();
+        let dy = (self.rho0 - (y - self.false_northing)) * self.n.signum();
+
+        let rho = dx.hypot(dy);
+        let q = (self.c - (rho * self.n / self.ellipsoid.semimajor_axis).powi(2)) / self.n;
+        let sin_beta = q / self.qp;
+
+        if sin_beta.abs() > 1. + 1e-12 {
+            return None;
+        }
+
+        let lat = authalic2geodetic(sin_beta.clamp(-1., 1.).asin(), &self.ellipsoid, false)?;
+        let lon = wrap_lon(self.lon0 + dx.atan2(dy) / self.n);
+
+        let lat = if deg { lat.to_degrees() } else { lat };
+        let lon = if deg { lon.to_degrees() } else { lon };
+
+        Some((lat, lon))
+    }
+
+    pub fn convergence(&self, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        if lat.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        let gamma = self.n * wrap_lon(lon - self.lon0);
+
+        Some(if deg { gamma.to_degrees() } else { gamma })
+    }
+
+    pub fn scale(&self, lat: f64, _lon: f64, deg: bool) -> Option<f64> {
+        let lat = if deg { lat.to_radians() } else { lat };
+
+        if lat.abs() >= FRAC_PI_2 {
+            return None;
+        }
+
+        let rho = self.rho(lat)?;
+
+        Some(self.n * rho / (self.ellipsoid.semimajor_axis * parallel_radius(lat, &self.ellipsoid)))
+    }
+}
@@ -0,0 +1,89 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::lox::meridian_arc;
+use crate::tmerc::wrap_lon;
+use crate::{rectifying2geodetic, rsphere, Ellipsoid};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CassiniSoldner {
+    pub lat0: f64,
+    pub lon0: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+    pub ellipsoid: Ellipsoid,
+}
+
+impl CassiniSoldner {
+    pub fn new(
+        lat0: f64,
+        lon0: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let lat0 = if deg { lat0.to_radians() } else { lat0 };
+        let lon0 = if deg { lon0.to_radians() } else { lon0 };
+
+        if lat0.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        Some(CassiniSoldner {
+            lat0,
+            lon0,
+            false_easting,
+            false_northing,
+            ellipsoid: ell.clone(),
+        })
+    }
+
+    pub fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        if lat.abs() >= FRAC_PI_2 {
+            return None;
+        }
+
+        let e2 = self.ellipsoid.eccentricity.powi(2);
+
+        let a = wrap_lon(lon - self.lon0) * lat.cos();
+        let t = lat.tan().powi(2);
+        let c = e2 * lat.cos().powi(2) / (1. - e2);
+        let nu = self.ellipsoid.semimajor_axis / (1. - e2 * lat.sin().powi(2)).sqrt();
+
+        let m = meridian_arc(self.lat0, lat, &self.ellipsoid, false)?;
+
+        let x = nu * (a - t * a.powi(3) / 6. - (8. - t + 8. * c) * t * a.powi(5) / 120.);
+        let y = m + nu * lat.tan() * (a.powi(2) / 2. + (5. - t + 6. * c) * a.powi(4) / 24.);
+
+        Some((self.false_easting + x, self.false_northing + y))
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)> {
+        let ell = &self.ellipsoid;
+        let e2 = ell.eccentricity.powi(2);
+
+        let m1 = meridian_arc(0., self.lat0, ell, false)? + y - self.false_northing;
+        let lat1 = rectifying2geodetic(m1 / rsphere::rectifying(ell), ell, false)?;
+
+        let w = 1. - e2 * lat1.sin().powi(2);
+        let nu1 = ell.semimajor_axis / w.sqrt();
+        let rho1 = ell.semimajor_axis * (1. - e2) / w.powf(1.5);
+        let t1 = lat1.tan().powi(2);
+        let d = (x - self.false_easting) / nu1;
+
+        let lat =
+            lat1 - nu1 * lat1.tan() / rho1 * (d.powi(2) / 2. - (1. + 3. * t1) * d.powi(4) / 24.);
+        let lon = self.lon0
+            + (d - t1 * d.powi(3) / 3. + (1. + 3. * t1) * t1 * d.powi(5) / 15.) / lat1.cos();
+
+        let lon = wrap_lon(lon);
+
+        let lat = if deg { lat.to_degrees() } else { lat };
+        let lon = if deg { lon.to_degrees() } else { lon };
+
+        Some((lat, lon))
+    }
+}
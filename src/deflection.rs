@@ -0,0 +1,265 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+use crate::{
+    aer2enu, ecef2geodetic, enu2aer, enu2uvw, geodetic2ecef, meridian, transverse, uvw2enu,
+    Ellipsoid, Geoid,
+};
+
+const STEP: f64 = 1e-5;
+
+fn direction(e: f64, n: f64, u: f64, deg: bool) -> (f64, f64) {
+    let az = e.atan2(n).rem_euclid(TAU);
+    let el = u.atan2(e.hypot(n));
+
+    if deg {
+        (az.to_degrees(), el.to_degrees())
+    } else {
+        (az, el)
+    }
+}
+
+pub fn deflection(
+    lat: f64,
+    lon: f64,
+    geoid: &dyn Geoid,
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64)> {
+    let lat = if deg { lat.to_radians() } else { lat };
+    let lon = if deg { lon.to_radians() } else { lon };
+
+    if lat.abs() > FRAC_PI_2 - STEP {
+        return None;
+    }
+
+    let dn_dlat = (geoid.undulation(lat + STEP, lon, false)?
+        - geoid.undulation(lat - STEP, lon, false)?)
+        / (2. * STEP);
+    let dn_dlon = (geoid.undulation(lat, lon + STEP, false)?
+        - geoid.undulation(lat, lon - STEP, false)?)
+        / (2. * STEP);
+
+    let xi = -dn_dlat / meridian(lat, ell, false)?;
+    let eta = -dn_dlon / (transverse(lat, ell, false)? * lat.cos());
+
+    let xi = if deg { xi.to_degrees() } else { xi };
+    let eta = if deg { eta.to_degrees() } else { eta };
+
+    Some((xi, eta))
+}
+
+pub fn geodetic2astronomic(
+    lat: f64,
+    lon: f64,
+    deflection: (f64, f64),
+    deg: bool,
+) -> Option<(f64, f64)> {
+    let lat = if deg { lat.to_radians() } else { lat };
+    let lon = if deg { lon.to_radians() } else { lon };
+    let (xi, eta) = if deg {
+        (deflection.0.to_radians(), deflection.1.to_radians())
+    } else {
+        deflection
+    };
+
+    if lat.abs() >= FRAC_PI_2 {
+        return None;
+    }
+
+    let alat = lat + xi;
+    let alon = lon + eta / lat.cos();
+
+    if alat.abs() > FRAC_PI_2 {
+        return None;
+    }
+
+    let alat = if deg { alat.to_degrees() } else { alat };
+    let alon = if deg { alon.to_degrees() } else { alon };
+
+    Some((alat, alon))
+}
+
+pub fn astronomic2geodetic(
+    alat: f64,
+    alon: f64,
+    deflection: (f64, f64),
+    deg: bool,
+) -> Option<(f64, f64)> {
+    let alat = if deg { alat.to_radians() } else { alat };
+    let alon = if deg { alon.to_radians() } else { alon };
+    let (xi, eta) = if deg {
+        (deflection.0.to_radians(), deflection.1.to_radians())
+    } else {
+        deflection
+    };
+
+    let lat = alat - xi;
+
+    if lat.abs() >= FRAC_PI_2 {
+        return None;
+    }
+
+    let lon = alon - eta / lat.cos();
+
+    let lat = if deg { lat.to_degrees() } else { lat };
+    let lon = if deg { lon.to_degrees() } else { lon };
+
+    Some((lat, lon))
+}
+
+pub fn laplace_azimuth(
+    az: f64,
+    el: f64,
+    lat: f64,
+    deflection: (f64, f64),
+    deg: bool,
+) -> Option<f64> {
+    let az = if deg { az.to_radians() } else { az };
+    let el = if deg { el.to_radians() } else { el };
+    let lat = if deg { lat.to_radians() } else { lat };
+    let (xi, eta) = if deg {
+        (deflection.0.to_radians(), deflection.1.to_radians())
+    } else {
+        deflection
+    };
+
+    if lat.abs() >= FRAC_PI_2 {
+        return None;
+    }
+
+    let gaz = az - eta * lat.tan() - (xi * az.sin() - eta * az.cos()) * el.tan();
+    let gaz = gaz.rem_euclid(TAU);
+
+    Some(if deg { gaz.to_degrees() } else { gaz })
+}
+
+pub fn astronomic2geodetic_azel(
+    az: f64,
+    el: f64,
+    lat: f64,
+    deflection: (f64, f64),
+    deg: bool,
+) -> Option<(f64, f64)> {
+    let (alat, alon) = geodetic2astronomic(lat, 0., deflection, deg)?;
+
+    let (e, n, u) = aer2enu(az, el, 1., deg);
+    let (x, y, z) = enu2uvw(e, n, u, alat, alon, deg);
+    let (e, n, u) = uvw2enu(x, y, z, lat, 0., deg);
+
+    Some(direction(e, n, u, deg))
+}
+
+pub fn geodetic2astronomic_azel(
+    az: f64,
+    el: f64,
+    lat: f64,
+    deflection: (f64, f64),
+    deg: bool,
+) -> Option<(f64, f64)> {
+    let (alat, alon) = geodetic2astronomic(lat, 0., deflection, deg)?;
+
+    let (e, n, u) = aer2enu(az, el, 1., deg);
+    let (x, y, z) = enu2uvw(e, n, u, lat, 0., deg);
+    let (e, n, u) = uvw2enu(x, y, z, alat, alon, deg);
+
+    Some(direction(e, n, u, deg))
+}
+
+pub fn ecef2enu_astronomic(
+    x: f64,
+    y: f64,
+    z: f64,
+    lat0: f64,
+    lon0: f64,
+    h0: f64,
+    deflection: (f64, f64),
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64, f64)> {
+    let (x0, y0, z0) = geodetic2ecef(lat0, lon0, h0, ell, deg)?;
+    let (alat, alon) = geodetic2astronomic(lat0, lon0, deflection, deg)?;
+
+    Some(uvw2enu(x - x0, y - y0, z - z0, alat, alon, deg))
+}
+
+pub fn enu2ecef_astronomic(
+    e: f64,
+    n: f64,
+    u: f64,
+    lat0: f64,
+    lon0: f64,
+    h0: f64,
+    deflection: (f64, f64),
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64, f64)> {
+    let (x0, y0, z0) = geodetic2ecef(lat0, lon0, h0, ell, deg)?;
+    let (alat, alon) = geodetic2astronomic(lat0, lon0, deflection, deg)?;
+    let (dx, dy, dz) = enu2uvw(e, n, u, alat, alon, deg);
+
+    Some((x0 + dx, y0 + dy, z0 + dz))
+}
+
+pub fn ecef2ned_astronomic(
+    x: f64,
+    y: f64,
+    z: f64,
+    lat0: f64,
+    lon0: f64,
+    h0: f64,
+    deflection: (f64, f64),
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64, f64)> {
+    let (e, n, u) = ecef2enu_astronomic(x, y, z, lat0, lon0, h0, deflection, ell, deg)?;
+
+    Some((n, e, -u))
+}
+
+pub fn ned2ecef_astronomic(
+    n: f64,
+    e: f64,
+    d: f64,
+    lat0: f64,
+    lon0: f64,
+    h0: f64,
+    deflection: (f64, f64),
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64, f64)> {
+    enu2ecef_astronomic(e, n, -d, lat0, lon0, h0, deflection, ell, deg)
+}
+
+pub fn aer2geodetic_astronomic(
+    az: f64,
+    el: f64,
+    srange: f64,
+    lat0: f64,
+    lon0: f64,
+    h0: f64,
+    deflection: (f64, f64),
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64, f64)> {
+    let (e, n, u) = aer2enu(az, el, srange, deg);
+    let (x, y, z) = enu2ecef_astronomic(e, n, u, lat0, lon0, h0, deflection, ell, deg)?;
+
+    Some(ecef2geodetic(x, y, z, ell, deg))
+}
+
+pub fn geodetic2aer_astronomic(
+    lat: f64,
+    lon: f64,
+    h: f64,
+    lat0: f64,
+    lon0: f64,
+    h0: f64,
+    deflection: (f64, f64),
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64, f64)> {
+    let (x, y, z) = geodetic2ecef(lat, lon, h, ell, deg)?;
+    let (e, n, u) = ecef2enu_astronomic(x, y, z, lat0, lon0, h0, deflection, ell, deg)?;
+
+    Some(enu2aer(e, n, u, deg))
+}
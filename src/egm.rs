@@ -0,0 +1,296 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use crate::gravity::{somigliana, zonal_harmonic};
+use crate::{geodetic2ecef, Ellipsoid};
+
+const SCALE: f64 = 1e-280;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GravityModel {
+    pub name: String,
+    pub zero_degree: f64,
+    gm: f64,
+    radius: f64,
+    max_degree: usize,
+    ellipsoid: Ellipsoid,
+    c: Vec<f64>,
+    s: Vec<f64>,
+    normal: Vec<f64>,
+    root: Vec<f64>,
+    sectoral: Vec<f64>,
+}
+
+fn index(n: usize, m: usize) -> usize {
+    n * (n + 1) / 2 + m
+}
+
+fn parse_number(token: &str) -> io::Result<f64> {
+    token
+        .replace(['D', 'd'], "E")
+        .parse()
+        .map_err(|_| invalid_data(&format!("invalid number `{}`", token)))
+}
+
+fn parse_index(token: &str) -> io::Result<usize> {
+    token
+        .parse()
+        .map_err(|_| invalid_data(&format!("invalid degree or order `{}`", token)))
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.to_string())
+}
+
+impl GravityModel {
+    pub fn new(gm: f64, radius: f64, max_degree: usize, name: &str) -> Self {
+        let size = index(max_degree, max_degree) + 1;
+        let ellipsoid = Ellipsoid::wgs84();
+
+        let root: Vec<f64> = (0..=2 * max_degree + 3)
+            .map(|k| (k as f64).sqrt())
+            .collect();
+
+        let mut sectoral = vec![SCALE; max_degree + 1];
+        for m in 1..=max_degree {
+            sectoral[m] = if m == 1 {
+                root[3] * SCALE
+            } else {
+                root[2 * m + 1] / root[2 * m] * sectoral[m - 1]
+            };
+        }
+
+        GravityModel {
+            name: name.to_string(),
+            gm,
+            radius,
+            max_degree,
+            zero_degree: 0.,
+            normal: normal_zonals(&ellipsoid, gm, radius, max_degree),
+            ellipsoid,
+            c: vec![0.; size],
+            s: vec![0.; size],
+            root,
+            sectoral,
+        }
+    }
+
+    pub fn with_ellipsoid(mut self, ell: &Ellipsoid) -> Self {
+        self.normal = normal_zonals(ell, self.gm, self.radius, self.max_degree);
+        self.ellipsoid = ell.clone();
+        self
+    }
+
+    pub fn gm(&self) -> f64 {
+        self.gm
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    pub fn max_degree(&self) -> usize {
+        self.max_degree
+    }
+
+    pub fn ellipsoid(&self) -> &Ellipsoid {
+        &self.ellipsoid
+    }
+
+    pub fn coefficient(&self, n: usize, m: usize) -> Option<(f64, f64)> {
+        if n > self.max_degree || m > n {
+            return None;
+        }
+
+        Some((self.c[index(n, m)], self.s[index(n, m)]))
+    }
+
+    pub fn set_coefficient(&mut self, n: usize, m: usize, c: f64, s: f64) -> Option<()> {
+        if n > self.max_degree || m > n {
+            return None;
+        }
+
+        self.c[index(n, m)] = c;
+        self.s[index(n, m)] = s;
+
+        Some(())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P, max_degree: usize) -> io::Result<Self> {
+        let file = File::open(path)?;
+
+        GravityModel::from_gfc(BufReader::new(file), max_degree)
+    }
+
+    pub fn from_gfc<R: BufRead>(reader: R, max_degree: usize) -> io::Result<Self> {
+        let mut name = String::new();
+        let mut gm = 3.986004415e14;
+        let mut radius = 6378136.3;
+        let mut header_degree = usize::MAX;
+        let mut in_header = true;
+        let mut entries = Vec::new();
+
+        for line in reader.lines() {
+            let line = line?;
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+
+            let Some(&key) = tokens.first() else {
+                continue;
+            };
+
+            let data = match key {
+                "gfc" | "gfct" => &tokens[1..],
+                _ if key.parse::<usize>().is_ok() => &tokens[..],
+                "end_of_head" => {
+                    in_header = false;
+                    continue;
+                }
+                _ if in_header => {
+                    let value = tokens.get(1).copied().unwrap_or_default();
+
+                    match key {
+                        "modelname" => name = value.to_string(),
+                        "earth_gravity_constant" => gm = parse_number(value)?,
+                        "radius" => radius = parse_number(value)?,
+                        "max_degree" => header_degree = parse_index(value)?,
+                        "norm" if value != "fully_normalized" => {
+                            return Err(invalid_data(&format!("unsupported norm `{}`", value)));
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+
+            in_header = false;
+
+            if data.len() < 4 {
+                return Err(invalid_data(&format!(
+                    "truncated coefficient line `{}`",
+                    line
+                )));
+            }
+
+            let n = parse_index(data[0])?;
+            let m = parse_index(data[1])?;
+
+            if m > n {
+                return Err(invalid_data(&format!("order {} exceeds degree {}", m, n)));
+            }
+
+            if n <= max_degree {
+                entries.push((n, m, parse_number(data[2])?, parse_number(data[3])?));
+            }
+        }
+
+        let degree = entries
+            .iter()
+            .map(|&(n, ..)| n)
+            .max()
+            .ok_or_else(|| invalid_data("no coefficients found"))?
+            .min(header_degree);
+
+        let mut model = GravityModel::new(gm, radius, degree, &name);
+
+        for (n, m, c, s) in entries {
+            model.set_coefficient(n, m, c, s);
+        }
+
+        Ok(model)
+    }
+
+    pub fn undulation(&self, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        let ell = &self.ellipsoid;
+        let (x, y, z) = geodetic2ecef(lat, lon, 0., ell, false)?;
+
+        let r = x.hypot(y).hypot(z);
+        let t = z / r;
+        let u = x.hypot(y) / r;
+
+        let potential = self.gm / r * self.synthesis(t, u, self.radius / r, lon);
+
+        Some(potential / somigliana(lat, ell, false)? + self.zero_degree)
+    }
+
+    fn synthesis(&self, t: f64, u: f64, q: f64, lon: f64) -> f64 {
+        let nmax = self.max_degree;
+        let root = &self.root;
+
+        let qn: Vec<f64> = (0..=nmax)
+            .scan(1., |p, _| {
+                let value = *p;
+                *p *= q;
+                Some(value)
+            })
+            .collect();
+
+        let mut sum = 0.;
+
+        for m in (0..=nmax).rev() {
+            let mut sum_c = 0.;
+            let mut sum_s = 0.;
+
+            let mut p2 = 0.;
+            let mut p1 = self.sectoral[m];
+
+            for n in m..=nmax {
+                let p = if n == m {
+                    p1
+                } else {
+                    let a = root[2 * n - 1] * root[2 * n + 1] / (root[n - m] * root[n + m]);
+                    let b = if n == m + 1 {
+                        0.
+                    } else {
+                        root[2 * n + 1] * root[n + m - 1] * root[n - m - 1]
+                            / (root[n - m] * root[n + m] * root[2 * n - 3])
+                    };
+                    let p = a * t * p1 - b * p2;
+                    p2 = p1;
+                    p1 = p;
+                    p
+                };
+
+                if n < 2 {
+                    continue;
+                }
+
+                let k = index(n, m);
+                let c = if m == 0 && n % 2 == 0 {
+                    self.c[k] - self.normal.get(n / 2).copied().unwrap_or(0.)
+                } else {
+                    self.c[k]
+                };
+
+                sum_c += qn[n] * c * p;
+                sum_s += qn[n] * self.s[k] * p;
+            }
+
+            let (sin_ml, cos_ml) = (m as f64 * lon).sin_cos();
+            sum = sum * u + sum_c * cos_ml + sum_s * sin_ml;
+        }
+
+        sum / SCALE
+    }
+}
+
+fn normal_zonals(ell: &Ellipsoid, gm: f64, radius: f64, nmax: usize) -> Vec<f64> {
+    let Some(physical) = ell.physical else {
+        return Vec::new();
+    };
+
+    (0..=(nmax / 2).min(10))
+        .map(|n| match zonal_harmonic(2 * n, ell) {
+            Some(j2n) => {
+                -j2n / (4. * n as f64 + 1.).sqrt()
+                    * (physical.gm / gm)
+                    * (ell.semimajor_axis / radius).powi(2 * n as i32)
+            }
+            None => 0.,
+        })
+        .collect()
+}
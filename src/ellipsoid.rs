@@ -1,3 +1,10 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PhysicalParameters {
+    pub gm: f64,
+    pub angular_velocity: f64,
+    pub j2: f64,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Ellipsoid {
     pub model: String,
@@ -7,6 +14,7 @@ pub struct Ellipsoid {
     pub flattening: f64,
     pub thirdflattening: f64,
     pub eccentricity: f64,
+    pub physical: Option<PhysicalParameters>,
 }
 
 impl Ellipsoid {
@@ -25,9 +33,33 @@ impl Ellipsoid {
             flattening,
             thirdflattening,
             eccentricity,
+            physical: None,
         }
     }
 
+    pub fn with_physical(mut self, gm: f64, angular_velocity: f64) -> Self {
+        let a = self.semimajor_axis;
+        let b = self.semiminor_axis;
+        let e2 = self.eccentricity.powi(2);
+
+        let j2 = if e2 > 0. {
+            let ep = (a.powi(2) - b.powi(2)).sqrt() / b;
+            let q0 = 0.5 * ((1. + 3. / ep.powi(2)) * ep.atan() - 3. / ep);
+            let m = angular_velocity.powi(2) * a.powi(2) * b / gm;
+
+            e2 / 3. * (1. - 2. * m * ep / (15. * q0))
+        } else {
+            0.
+        };
+
+        self.physical = Some(PhysicalParameters {
+            gm,
+            angular_velocity,
+            j2,
+        });
+        self
+    }
+
     pub fn maupertuis() -> Ellipsoid {
         Ellipsoid::new(6397300.0, 6363806.283, "Maupertuis (1738)", "maupertuis")
     }
@@ -56,6 +88,9 @@ impl Ellipsoid {
     pub fn airy() -> Ellipsoid {
         Ellipsoid::new(6377563.396, 6356256.909, "Airy (1830)", "airy")
     }
+    pub fn airy_modified() -> Ellipsoid {
+        Ellipsoid::new(6377340.189, 6356034.448, "Airy Modified (1849)", "airy_modified")
+    }
     pub fn bessel() -> Ellipsoid {
         Ellipsoid::new(6377397.155, 6356078.963, "Bessel (1841)", "bessel")
     }
@@ -111,18 +146,22 @@ impl Ellipsoid {
     }
     pub fn grs67() -> Ellipsoid {
         Ellipsoid::new(6378160.0, 6356774.516, "GRS-67 (1967)", "grs67")
+            .with_physical(3.98603e14, 7.2921151467e-5)
     }
     pub fn sa1969() -> Ellipsoid {
         Ellipsoid::new(6378160.0, 6356774.719, "South American (1969)", "sa1969")
     }
     pub fn wgs72() -> Ellipsoid {
         Ellipsoid::new(6378135.0, 6356750.52001609, "WGS-72 (1972)", "wgs72")
+            .with_physical(3.986008e14, 7.292115147e-5)
     }
     pub fn grs80() -> Ellipsoid {
         Ellipsoid::new(6378137.0, 6356752.31414036, "GRS-80 (1979)", "grs80")
+            .with_physical(3.986005e14, 7.292115e-5)
     }
     pub fn wgs84() -> Ellipsoid {
         Ellipsoid::new(6378137.0, 6356752.31424518, "WGS-84 (1984)", "wgs84")
+            .with_physical(3.986004418e14, 7.292115e-5)
     }
     pub fn wgs84_mean() -> Ellipsoid {
         Ellipsoid::new(
@@ -137,12 +176,14 @@ impl Ellipsoid {
     }
     pub fn pz90_11() -> Ellipsoid {
         Ellipsoid::new(6378136.0, 6356751.3618, "ПЗ-90 (2011)", "pz90.11")
+            .with_physical(3.986004418e14, 7.292115e-5)
     }
     pub fn iers2003() -> Ellipsoid {
         Ellipsoid::new(6378136.6, 6356751.9, "IERS (2003)", "iers2003")
     }
     pub fn gsk2011() -> Ellipsoid {
         Ellipsoid::new(6378136.5, 6356751.758, "ГСК (2011)", "gsk2011")
+            .with_physical(3.986004415e14, 7.292115e-5)
     }
     pub fn mercury() -> Ellipsoid {
         Ellipsoid::new(2440500.0, 2438300.0, "Mercury", "mercury")
@@ -183,6 +224,7 @@ impl Ellipsoid {
             "everest1830m" => Some(Ellipsoid::everest1830m()),
             "everest1967" => Some(Ellipsoid::everest1967()),
             "airy" => Some(Ellipsoid::airy()),
+            "airy_modified" => Some(Ellipsoid::airy_modified()),
             "bessel" => Some(Ellipsoid::bessel()),
             "clarke1866" => Some(Ellipsoid::clarke1866()),
             "clarke1878" => Some(Ellipsoid::clarke1878()),
@@ -0,0 +1,97 @@
+use crate::rsphere::authalic;
+use crate::tmerc::wrap_lon;
+use crate::{authalic2geodetic, geodetic2authalic, Ellipsoid};
+
+const A1: f64 = 1.340264;
+const A2: f64 = -0.081106;
+const A3: f64 = 0.000893;
+const A4: f64 = 0.003796;
+
+fn polynomial(theta: f64) -> f64 {
+    let t2 = theta.powi(2);
+
+    theta * (A1 + t2 * (A2 + t2.powi(2) * (A3 + A4 * t2)))
+}
+
+fn derivative(theta: f64) -> f64 {
+    let t2 = theta.powi(2);
+
+    A1 + 3. * A2 * t2 + t2.powi(3) * (7. * A3 + 9. * A4 * t2)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EqualEarth {
+    pub lon0: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+    pub ellipsoid: Ellipsoid,
+    radius: f64,
+}
+
+impl EqualEarth {
+    pub fn new(
+        lon0: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let lon0 = if deg { lon0.to_radians() } else { lon0 };
+
+        Some(EqualEarth {
+            lon0,
+            false_easting,
+            false_northing,
+            ellipsoid: ell.clone(),
+            radius: authalic(ell),
+        })
+    }
+
+    pub fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        let beta = geodetic2authalic(lat, &self.ellipsoid, false)?;
+        let theta = (3f64.sqrt() / 2. * beta.sin()).asin();
+
+        let x =
+            2. * 3f64.sqrt() * wrap_lon(lon - self.lon0) * theta.cos() / (3. * derivative(theta));
+        let y = polynomial(theta);
+
+        Some((
+            self.false_easting + self.radius * x,
+            self.false_northing + self.radius * y,
+        ))
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)> {
+        let x = (x - self.false_easting) / self.radius;
+        let y = (y - self.false_northing) / self.radius;
+
+        if y.abs() > polynomial(std::f64::consts::FRAC_PI_3) + 1e-12 {
+            return None;
+        }
+
+        let mut theta = y / A1;
+
+        for _ in 0..20 {
+            let delta = (polynomial(theta) - y) / derivative(theta);
+            theta -= delta;
+
+            if delta.abs() < 1e-14 {
+                break;
+            }
+        }
+
+        let dlon = 3. * x * derivative(theta) / (2. * 3f64.sqrt() * theta.cos());
+        let beta = (2. * theta.sin() / 3f64.sqrt()).clamp(-1., 1.).asin();
+
+        let lat = authalic2geodetic(beta, &self.ellipsoid, false)?;
+        let lon = wrap_lon(self.lon0 + dlon);
+
+        let lat = if deg { lat.to_degrees() } else { lat };
+        let lon = if deg { lon.to_degrees() } else { lon };
+
+        Some((lat, lon))
+    }
+}
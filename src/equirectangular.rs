@@ -0,0 +1,73 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::lox::meridian_arc;
+use crate::rcurve::parallel_radius;
+use crate::tmerc::wrap_lon;
+use crate::{rectifying2geodetic, rsphere, Ellipsoid};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Equirectangular {
+    pub lat_ts: f64,
+    pub lon0: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+    pub ellipsoid: Ellipsoid,
+    radius: f64,
+}
+
+impl Equirectangular {
+    pub fn new(
+        lat_ts: f64,
+        lon0: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let lat_ts = if deg { lat_ts.to_radians() } else { lat_ts };
+        let lon0 = if deg { lon0.to_radians() } else { lon0 };
+
+        if lat_ts.abs() >= FRAC_PI_2 {
+            return None;
+        }
+
+        let radius = ell.semimajor_axis * parallel_radius(lat_ts, ell);
+
+        Some(Equirectangular {
+            lat_ts,
+            lon0,
+            false_easting,
+            false_northing,
+            ellipsoid: ell.clone(),
+            radius,
+        })
+    }
+
+    pub fn plate_carree(ell: &Ellipsoid) -> Self {
+        Self::new(0., 0., 0., 0., ell, false).unwrap()
+    }
+
+    pub fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        let y = meridian_arc(0., lat, &self.ellipsoid, false)?;
+
+        Some((
+            self.false_easting + self.radius * wrap_lon(lon - self.lon0),
+            self.false_northing + y,
+        ))
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)> {
+        let mu = (y - self.false_northing) / rsphere::rectifying(&self.ellipsoid);
+
+        let lat = rectifying2geodetic(mu, &self.ellipsoid, false)?;
+        let lon = wrap_lon(self.lon0 + (x - self.false_easting) / self.radius);
+
+        let lat = if deg { lat.to_degrees() } else { lat };
+        let lon = if deg { lon.to_degrees() } else { lon };
+
+        Some((lat, lon))
+    }
+}
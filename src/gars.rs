@@ -0,0 +1,196 @@
+const LETTERS: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ";
+const MAX_CELLS: usize = 1 << 20;
+
+fn cells_per_band(minutes: u8) -> Option<u32> {
+    match minutes {
+        30 => Some(1),
+        15 => Some(2),
+        5 => Some(6),
+        _ => None,
+    }
+}
+
+fn encode(x: u32, y: u32, minutes: u8) -> String {
+    let n = cells_per_band(minutes).unwrap();
+    let (band_x, band_y) = (x / n, y / n);
+
+    let mut code = format!(
+        "{:03}{}{}",
+        band_x + 1,
+        LETTERS[(band_y / 24) as usize] as char,
+        LETTERS[(band_y % 24) as usize] as char
+    );
+
+    if minutes <= 15 {
+        let (qx, qy) = (x % n / (n / 2), y % n / (n / 2));
+        code.push((b'1' + (1 - qy as u8) * 2 + qx as u8) as char);
+    }
+
+    if minutes == 5 {
+        let (kx, ky) = (x % 3, y % 3);
+        code.push((b'1' + (2 - ky as u8) * 3 + kx as u8) as char);
+    }
+
+    code
+}
+
+fn decode(code: &str) -> Option<(u32, u32, u8)> {
+    let code = code.trim().to_ascii_uppercase();
+    let bytes = code.as_bytes();
+
+    let minutes = match bytes.len() {
+        5 => 30,
+        6 => 15,
+        7 => 5,
+        _ => return None,
+    };
+
+    if !bytes[..3].iter().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let band_x: u32 = code[..3].parse().ok()?;
+    let l1 = LETTERS.iter().position(|&l| l == bytes[3])? as u32;
+    let l2 = LETTERS.iter().position(|&l| l == bytes[4])? as u32;
+    let band_y = l1 * 24 + l2;
+
+    if !(1..=720).contains(&band_x) || band_y >= 360 {
+        return None;
+    }
+
+    let n = cells_per_band(minutes)?;
+    let (mut x, mut y) = ((band_x - 1) * n, band_y * n);
+
+    if minutes <= 15 {
+        let quadrant = (bytes[5] as u32).checked_sub(b'1' as u32)?;
+
+        if quadrant > 3 {
+            return None;
+        }
+
+        x += quadrant % 2 * (n / 2);
+        y += (1 - quadrant / 2) * (n / 2);
+    }
+
+    if minutes == 5 {
+        let key = (bytes[6] as u32).checked_sub(b'1' as u32)?;
+
+        if key > 8 {
+            return None;
+        }
+
+        x += key % 3;
+        y += 2 - key / 3;
+    }
+
+    Some((x, y, minutes))
+}
+
+fn size(minutes: u8) -> f64 {
+    minutes as f64 / 60.
+}
+
+pub fn geodetic2gars(lat: f64, lon: f64, minutes: u8, deg: bool) -> Option<String> {
+    let lat = if deg { lat } else { lat.to_degrees() };
+    let lon = if deg { lon } else { lon.to_degrees() };
+
+    if lat.abs() > 90. {
+        return None;
+    }
+
+    let n = cells_per_band(minutes)?;
+    let size = size(minutes);
+
+    let x = (((lon + 180.).rem_euclid(360.) / size) as u32).min(720 * n - 1);
+    let y = (((lat + 90.) / size) as u32).min(360 * n - 1);
+
+    Some(encode(x, y, minutes))
+}
+
+pub fn gars_bbox(code: &str, deg: bool) -> Option<(f64, f64, f64, f64)> {
+    let (x, y, minutes) = decode(code)?;
+    let size = size(minutes);
+
+    let south = -90. + y as f64 * size;
+    let west = -180. + x as f64 * size;
+    let (north, east) = (south + size, west + size);
+
+    if deg {
+        Some((south, west, north, east))
+    } else {
+        Some((
+            south.to_radians(),
+            west.to_radians(),
+            north.to_radians(),
+            east.to_radians(),
+        ))
+    }
+}
+
+pub fn gars2geodetic(code: &str, deg: bool) -> Option<(f64, f64)> {
+    let (south, west, north, east) = gars_bbox(code, deg)?;
+
+    Some(((south + north) / 2., (west + east) / 2.))
+}
+
+pub fn gars_polygon(code: &str, deg: bool) -> Option<[(f64, f64); 4]> {
+    let (south, west, north, east) = gars_bbox(code, deg)?;
+
+    Some([(south, west), (south, east), (north, east), (north, west)])
+}
+
+pub fn gars_cells(
+    south: f64,
+    west: f64,
+    north: f64,
+    east: f64,
+    minutes: u8,
+    deg: bool,
+) -> Option<Vec<String>> {
+    let south = if deg { south } else { south.to_degrees() };
+    let west = if deg { west } else { west.to_degrees() };
+    let north = if deg { north } else { north.to_degrees() };
+    let east = if deg { east } else { east.to_degrees() };
+
+    if south > north || south < -90. || north > 90. {
+        return None;
+    }
+
+    let n = cells_per_band(minutes)?;
+    let size = size(minutes);
+    let (nx, ny) = (720 * n as i64, 360 * n as i64);
+
+    let span = if east - west >= 360. {
+        360.
+    } else {
+        (east - west).rem_euclid(360.)
+    };
+
+    let west = (west + 180.).rem_euclid(360.);
+    let east = west + span;
+
+    let range = |lo: f64, hi: f64, max: i64| {
+        let i0 = (lo / size).floor() as i64;
+        let i1 = ((hi / size).ceil() as i64 - 1).max(i0);
+
+        (i0, i1.min(i0 + max - 1))
+    };
+
+    let (x0, x1) = range(west, east, nx);
+    let (y0, y1) = range(south + 90., north + 90., ny);
+    let (y0, y1) = (y0.min(ny - 1), y1.min(ny - 1));
+
+    if ((x1 - x0 + 1) * (y1 - y0 + 1)) as usize > MAX_CELLS {
+        return None;
+    }
+
+    let mut cells = Vec::new();
+
+    for y in y0..=y1 {
+        for x in x0..=x1 {
+            cells.push(encode(x.rem_euclid(nx) as u32, y as u32, minutes));
+        }
+    }
+
+    Some(cells)
+}
@@ -0,0 +1,393 @@
+use std::collections::BTreeSet;
+
+use crate::{vdist, Ellipsoid};
+
+const BASE32: &[u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+const MAX_PRECISION: usize = 12;
+const MAX_CELLS: u64 = 1 << 20;
+
+fn bits(precision: usize) -> (u32, u32) {
+    let total = 5 * precision as u32;
+
+    (total.div_ceil(2), total / 2)
+}
+
+fn encode(x: u64, y: u64, precision: usize) -> String {
+    let (mut xbits, mut ybits) = bits(precision);
+    let mut hash = String::with_capacity(precision);
+    let mut ch = 0;
+
+    for i in 0..5 * precision {
+        let bit = if i.is_multiple_of(2) {
+            xbits -= 1;
+            (x >> xbits) & 1
+        } else {
+            ybits -= 1;
+            (y >> ybits) & 1
+        };
+
+        ch = (ch << 1) | bit as usize;
+
+        if i % 5 == 4 {
+            hash.push(BASE32[ch] as char);
+            ch = 0;
+        }
+    }
+
+    hash
+}
+
+fn decode(hash: &str) -> Option<(u64, u64, usize)> {
+    let precision = hash.len();
+
+    if precision == 0 || precision > MAX_PRECISION {
+        return None;
+    }
+
+    let (mut x, mut y) = (0u64, 0u64);
+    let mut i = 0;
+
+    for c in hash.chars() {
+        let value = BASE32
+            .iter()
+            .position(|&b| b as char == c.to_ascii_lowercase())?;
+
+        for shift in (0..5).rev() {
+            let bit = ((value >> shift) & 1) as u64;
+
+            if i % 2 == 0 {
+                x = (x << 1) | bit;
+            } else {
+                y = (y << 1) | bit;
+            }
+
+            i += 1;
+        }
+    }
+
+    Some((x, y, precision))
+}
+
+fn cell_size(precision: usize) -> (f64, f64) {
+    let (xbits, ybits) = bits(precision);
+
+    (180. / (1u64 << ybits) as f64, 360. / (1u64 << xbits) as f64)
+}
+
+fn cell_index(lat: f64, lon: f64, precision: usize) -> (u64, u64) {
+    let (xbits, ybits) = bits(precision);
+    let (nx, ny) = (1u64 << xbits, 1u64 << ybits);
+
+    let x = ((lon + 180.).rem_euclid(360.) / 360. * nx as f64) as u64;
+    let y = ((lat + 90.) / 180. * ny as f64) as u64;
+
+    (x.min(nx - 1), y.min(ny - 1))
+}
+
+fn cell_bbox(x: u64, y: u64, precision: usize) -> (f64, f64, f64, f64) {
+    let (dlat, dlon) = cell_size(precision);
+    let south = -90. + y as f64 * dlat;
+    let west = -180. + x as f64 * dlon;
+
+    (south, west, south + dlat, west + dlon)
+}
+
+pub fn geodetic2geohash(lat: f64, lon: f64, precision: usize, deg: bool) -> Option<String> {
+    let lat = if deg { lat } else { lat.to_degrees() };
+    let lon = if deg { lon } else { lon.to_degrees() };
+
+    if lat.abs() > 90. || precision == 0 || precision > MAX_PRECISION {
+        return None;
+    }
+
+    let (x, y) = cell_index(lat, lon, precision);
+
+    Some(encode(x, y, precision))
+}
+
+pub fn geohash_bbox(hash: &str, deg: bool) -> Option<(f64, f64, f64, f64)> {
+    let (x, y, precision) = decode(hash)?;
+    let (south, west, north, east) = cell_bbox(x, y, precision);
+
+    if deg {
+        Some((south, west, north, east))
+    } else {
+        Some((
+            south.to_radians(),
+            west.to_radians(),
+            north.to_radians(),
+            east.to_radians(),
+        ))
+    }
+}
+
+pub fn geohash2geodetic(hash: &str, deg: bool) -> Option<(f64, f64)> {
+    let (south, west, north, east) = geohash_bbox(hash, deg)?;
+
+    Some(((south + north) / 2., (west + east) / 2.))
+}
+
+fn neighbour(hash: &str, north: i64, east: i64) -> Option<String> {
+    let (x, y, precision) = decode(hash)?;
+    let (xbits, ybits) = bits(precision);
+
+    let y = y as i64 + north;
+
+    if y < 0 || y >= 1 << ybits {
+        return None;
+    }
+
+    let x = (x as i64 + east).rem_euclid(1 << xbits);
+
+    Some(encode(x as u64, y as u64, precision))
+}
+
+pub fn geohash_neighbours(hash: &str) -> Option<[Option<String>; 8]> {
+    decode(hash)?;
+
+    Some(
+        [
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+        ]
+        .map(|(north, east)| neighbour(hash, north, east)),
+    )
+}
+
+fn distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64, ell: &Ellipsoid) -> f64 {
+    vdist(lat1, lon1, lat2, lon2, ell, true).map_or(f64::INFINITY, |(s, _)| s)
+}
+
+fn wrap(lon: f64) -> f64 {
+    (lon + 180.).rem_euclid(360.) - 180.
+}
+
+fn min_distance(lat: f64, lon: f64, bbox: (f64, f64, f64, f64), ell: &Ellipsoid) -> f64 {
+    let (south, west, north, east) = bbox;
+    let offset = wrap(lon - west);
+    let inside_lon = offset >= 0. && offset <= east - west;
+
+    if inside_lon && lat >= south && lat <= north {
+        return 0.;
+    }
+
+    let nearest_lon = if inside_lon {
+        lon
+    } else if wrap(lon - east).abs() < wrap(lon - west).abs() {
+        east
+    } else {
+        west
+    };
+
+    let along_meridian = |meridian: f64| {
+        let (mut lo, mut hi) = (south, north);
+
+        for _ in 0..60 {
+            let m1 = lo + (hi - lo) / 3.;
+            let m2 = hi - (hi - lo) / 3.;
+
+            if distance(lat, lon, m1, meridian, ell) < distance(lat, lon, m2, meridian, ell) {
+                hi = m2;
+            } else {
+                lo = m1;
+            }
+        }
+
+        distance(lat, lon, (lo + hi) / 2., meridian, ell)
+    };
+
+    [
+        distance(lat, lon, south, nearest_lon, ell),
+        distance(lat, lon, north, nearest_lon, ell),
+        along_meridian(west),
+        along_meridian(east),
+    ]
+    .into_iter()
+    .fold(f64::INFINITY, f64::min)
+}
+
+pub fn geohash_circle(
+    lat: f64,
+    lon: f64,
+    radius: f64,
+    precision: usize,
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<Vec<String>> {
+    let lat = if deg { lat } else { lat.to_degrees() };
+    let lon = if deg { lon } else { lon.to_degrees() };
+
+    if lat.abs() > 90. || radius < 0. || precision == 0 || precision > MAX_PRECISION {
+        return None;
+    }
+
+    let (dlat, dlon) = cell_size(precision);
+    let (xbits, _) = bits(precision);
+
+    let angle = (radius / ell.semiminor_axis).to_degrees();
+    let south = (lat - angle - dlat).max(-90.);
+    let north = (lat + angle + dlat).min(90.);
+
+    let ratio = (radius / ell.semiminor_axis).sin() / lat.to_radians().cos();
+
+    let (x0, nx) = if north >= 90. || south <= -90. || ratio >= 1. || angle >= 90. {
+        (0, 1i64 << xbits)
+    } else {
+        let half = ratio.asin().to_degrees() + dlon;
+        let x0 = ((lon - half + 180.) / dlon).floor() as i64;
+        let x1 = ((lon + half + 180.) / dlon).floor() as i64;
+
+        (x0, (x1 - x0 + 1).min(1 << xbits))
+    };
+
+    let (_, y0) = cell_index(south, lon, precision);
+    let (_, y1) = cell_index(north, lon, precision);
+
+    if (y1 - y0 + 1) * nx as u64 > MAX_CELLS {
+        return None;
+    }
+
+    let mut cells = BTreeSet::new();
+
+    for y in y0..=y1 {
+        for i in 0..nx {
+            let x = (x0 + i).rem_euclid(1 << xbits) as u64;
+            let bbox = cell_bbox(x, y, precision);
+
+            let (clat, clon) = ((bbox.0 + bbox.2) / 2., (bbox.1 + bbox.3) / 2.);
+            let centre = distance(lat, lon, clat, clon, ell);
+            let half_diagonal = distance(clat, clon, bbox.0, bbox.1, ell)
+                .max(distance(clat, clon, bbox.2, bbox.1, ell));
+
+            if centre <= radius
+                || (centre <= radius + half_diagonal && min_distance(lat, lon, bbox, ell) <= radius)
+            {
+                cells.insert(encode(x, y, precision));
+            }
+        }
+    }
+
+    Some(cells.into_iter().collect())
+}
+
+fn inside(lat: f64, lon: f64, polygon: &[(f64, f64)]) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len() - 1;
+
+    for i in 0..polygon.len() {
+        let (lat_i, lon_i) = polygon[i];
+        let (lat_j, lon_j) = polygon[j];
+
+        if (lat_i > lat) != (lat_j > lat)
+            && lon < (lon_j - lon_i) * (lat - lat_i) / (lat_j - lat_i) + lon_i
+        {
+            inside = !inside;
+        }
+
+        j = i;
+    }
+
+    inside
+}
+
+fn cross(o: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+}
+
+fn segments_intersect(p1: (f64, f64), p2: (f64, f64), q1: (f64, f64), q2: (f64, f64)) -> bool {
+    let d1 = cross(q1, q2, p1);
+    let d2 = cross(q1, q2, p2);
+    let d3 = cross(p1, p2, q1);
+    let d4 = cross(p1, p2, q2);
+
+    d1 * d2 <= 0. && d3 * d4 <= 0.
+}
+
+pub fn geohash_polygon(polygon: &[(f64, f64)], precision: usize, deg: bool) -> Option<Vec<String>> {
+    if polygon.len() < 3 || precision == 0 || precision > MAX_PRECISION {
+        return None;
+    }
+
+    let mut ring: Vec<(f64, f64)> = Vec::with_capacity(polygon.len());
+
+    for &(lat, lon) in polygon {
+        let lat = if deg { lat } else { lat.to_degrees() };
+        let lon = if deg { lon } else { lon.to_degrees() };
+
+        if lat.abs() > 90. {
+            return None;
+        }
+
+        let lon = match ring.last() {
+            Some(&(_, prev)) => prev + wrap(lon - prev),
+            None => lon,
+        };
+
+        ring.push((lat, lon));
+    }
+
+    let closing = ring[ring.len() - 1].1 + wrap(ring[0].1 - ring[ring.len() - 1].1);
+
+    if (closing - ring[0].1).abs() > 1e-9 {
+        return None;
+    }
+
+    let (dlat, dlon) = cell_size(precision);
+    let (xbits, _) = bits(precision);
+
+    let fold = |f: fn(f64, f64) -> f64, i: usize, init: f64| {
+        ring.iter()
+            .map(|p| if i == 0 { p.0 } else { p.1 })
+            .fold(init, f)
+    };
+
+    let (south, north) = (fold(f64::min, 0, 90.), fold(f64::max, 0, -90.));
+    let (west, east) = (
+        fold(f64::min, 1, f64::INFINITY),
+        fold(f64::max, 1, -f64::INFINITY),
+    );
+
+    let x0 = ((west + 180.) / dlon).floor() as i64;
+    let x1 = ((east + 180.) / dlon).floor() as i64;
+    let (_, y0) = cell_index(south, 0., precision);
+    let (_, y1) = cell_index(north, 0., precision);
+
+    if (y1 - y0 + 1) * (x1 - x0 + 1) as u64 > MAX_CELLS {
+        return None;
+    }
+
+    let mut cells = BTreeSet::new();
+
+    for y in y0..=y1 {
+        for xi in x0..=x1 {
+            let s = -90. + y as f64 * dlat;
+            let w = -180. + xi as f64 * dlon;
+            let (n, e) = (s + dlat, w + dlon);
+
+            let corners = [(s, w), (s, e), (n, e), (n, w)];
+
+            let hit = inside(s + dlat / 2., w + dlon / 2., &ring)
+                || ring
+                    .iter()
+                    .any(|&(lat, lon)| lat >= s && lat <= n && lon >= w && lon <= e)
+                || (0..ring.len()).any(|i| {
+                    let (a, b) = (ring[i], ring[(i + 1) % ring.len()]);
+
+                    (0..4).any(|k| segments_intersect(a, b, corners[k], corners[(k + 1) % 4]))
+                });
+
+            if hit {
+                let x = xi.rem_euclid(1 << xbits) as u64;
+                cells.insert(encode(x, y, precision));
+            }
+        }
+    }
+
+    Some(cells.into_iter().collect())
+}
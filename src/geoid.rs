@@ -0,0 +1,320 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+
+use crate::{ecef2geodetic, geodetic2ecef, geodetic2enu, Ellipsoid, GravityModel};
+
+const MAX_NODES: usize = 1 << 28;
+
+pub trait Geoid {
+    fn undulation(&self, lat: f64, lon: f64, deg: bool) -> Option<f64>;
+}
+
+impl Geoid for GravityModel {
+    fn undulation(&self, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+        GravityModel::undulation(self, lat, lon, deg)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    Bilinear,
+    Bicubic,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeoidGrid {
+    pub south: f64,
+    pub west: f64,
+    pub dlat: f64,
+    pub dlon: f64,
+    pub nlat: usize,
+    pub nlon: usize,
+    pub interpolation: Interpolation,
+    data: Vec<f32>,
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.to_string())
+}
+
+fn cubic(p: [f64; 4], t: f64) -> f64 {
+    p[1] + 0.5
+        * t
+        * (p[2] - p[0]
+            + t * (2. * p[0] - 5. * p[1] + 4. * p[2] - p[3]
+                + t * (3. * (p[1] - p[2]) + p[3] - p[0])))
+}
+
+impl GeoidGrid {
+    pub fn new(
+        south: f64,
+        west: f64,
+        dlat: f64,
+        dlon: f64,
+        nlat: usize,
+        nlon: usize,
+        data: Vec<f32>,
+    ) -> Option<Self> {
+        if nlat < 2 || nlon < 2 || dlat <= 0. || dlon <= 0. || data.len() != nlat * nlon {
+            return None;
+        }
+
+        Some(GeoidGrid {
+            south,
+            west,
+            dlat,
+            dlon,
+            nlat,
+            nlon,
+            interpolation: Interpolation::Bilinear,
+            data,
+        })
+    }
+
+    pub fn load_gtx<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        GeoidGrid::from_gtx(BufReader::new(File::open(path)?))
+    }
+
+    pub fn load_grd<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        GeoidGrid::from_grd(BufReader::new(File::open(path)?))
+    }
+
+    pub fn from_gtx<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut header = [0u8; 40];
+        reader.read_exact(&mut header)?;
+
+        let float = |i: usize| f64::from_be_bytes(header[8 * i..8 * i + 8].try_into().unwrap());
+        let int = |i: usize| i32::from_be_bytes(header[i..i + 4].try_into().unwrap());
+
+        let (south, west, dlat, dlon) = (float(0), float(1), float(2), float(3));
+        let (nlat, nlon) = (int(32), int(36));
+
+        if nlat < 2 || nlon < 2 {
+            return Err(invalid_data("invalid gtx grid dimensions"));
+        }
+
+        let (nlat, nlon) = (nlat as usize, nlon as usize);
+        let size = nlat
+            .checked_mul(nlon)
+            .filter(|&n| n <= MAX_NODES)
+            .ok_or_else(|| invalid_data("gtx grid too large"))?;
+
+        let mut bytes = Vec::new();
+        reader.take(4 * size as u64 + 1).read_to_end(&mut bytes)?;
+
+        if bytes.len() != 4 * size {
+            return Err(invalid_data("gtx data does not match header"));
+        }
+
+        let data = bytes
+            .chunks_exact(4)
+            .map(|b| f32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+            .map(|v| if v == -88.8888 { f32::NAN } else { v })
+            .collect();
+
+        let west = if west > 180. { west - 360. } else { west };
+
+        GeoidGrid::new(south, west, dlat, dlon, nlat, nlon, data)
+            .ok_or_else(|| invalid_data("invalid gtx grid header"))
+    }
+
+    pub fn from_grd<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut values = Vec::new();
+
+        for line in reader.lines() {
+            for token in line?.split_whitespace() {
+                let value: f64 = token
+                    .parse()
+                    .map_err(|_| invalid_data(&format!("invalid number `{}`", token)))?;
+                values.push(value);
+            }
+        }
+
+        if values.len() < 6 {
+            return Err(invalid_data("truncated grd header"));
+        }
+
+        let (south, north, west, east, dlat, dlon) = (
+            values[0], values[1], values[2], values[3], values[4], values[5],
+        );
+
+        if values[..6].iter().any(|v| !v.is_finite()) || dlat <= 0. || dlon <= 0. {
+            return Err(invalid_data("invalid grd grid header"));
+        }
+
+        let count = |span: f64, step: f64| {
+            let n = (span / step).round();
+
+            if (0. ..MAX_NODES as f64).contains(&n) {
+                (n as usize).checked_add(1)
+            } else {
+                None
+            }
+        };
+
+        let (nlat, nlon, size) = count(north - south, dlat)
+            .zip(count(east - west, dlon))
+            .and_then(|(nlat, nlon)| Some((nlat, nlon, nlat.checked_mul(nlon)?)))
+            .filter(|&(_, _, size)| size <= MAX_NODES)
+            .ok_or_else(|| invalid_data("grd grid too large"))?;
+
+        if values.len() - 6 != size {
+            return Err(invalid_data("grd data does not match header"));
+        }
+
+        let mut data = Vec::with_capacity(size);
+        for row in (0..nlat).rev() {
+            let start = 6 + row * nlon;
+            data.extend(values[start..start + nlon].iter().map(|&v| v as f32));
+        }
+
+        GeoidGrid::new(south, west, dlat, dlon, nlat, nlon, data)
+            .ok_or_else(|| invalid_data("invalid grd grid header"))
+    }
+
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    fn period(&self) -> Option<usize> {
+        let period = (360. / self.dlon).round() as usize;
+
+        if self.nlon >= period && ((period as f64) * self.dlon - 360.).abs() < 1e-9 {
+            Some(period)
+        } else {
+            None
+        }
+    }
+
+    fn value(&self, row: isize, col: isize) -> f64 {
+        let row = row.clamp(0, self.nlat as isize - 1) as usize;
+        let col = match self.period() {
+            Some(period) => col.rem_euclid(period as isize) as usize,
+            None => col.clamp(0, self.nlon as isize - 1) as usize,
+        };
+
+        self.data[row * self.nlon + col] as f64
+    }
+}
+
+impl Geoid for GeoidGrid {
+    fn undulation(&self, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+        let lat = if deg { lat } else { lat.to_degrees() };
+        let lon = if deg { lon } else { lon.to_degrees() };
+
+        let y = (lat - self.south) / self.dlat;
+        let mut x = (lon - self.west).rem_euclid(360.) / self.dlon;
+
+        let eps = 1e-9;
+        if self.period().is_none() && x > self.nlon as f64 - 1. + eps {
+            x -= 360. / self.dlon;
+        }
+
+        if !(-eps..=self.nlat as f64 - 1. + eps).contains(&y) {
+            return None;
+        }
+        if self.period().is_none() && !(-eps..=self.nlon as f64 - 1. + eps).contains(&x) {
+            return None;
+        }
+
+        let row = (y.floor() as isize).clamp(0, self.nlat as isize - 2);
+        let col = match self.period() {
+            Some(_) => x.floor() as isize,
+            None => (x.floor() as isize).clamp(0, self.nlon as isize - 2),
+        };
+        let (ty, tx) = (y - row as f64, x - col as f64);
+
+        let n = match self.interpolation {
+            Interpolation::Bilinear => {
+                let v00 = self.value(row, col);
+                let v01 = self.value(row, col + 1);
+                let v10 = self.value(row + 1, col);
+                let v11 = self.value(row + 1, col + 1);
+
+                (1. - ty) * ((1. - tx) * v00 + tx * v01) + ty * ((1. - tx) * v10 + tx * v11)
+            }
+            Interpolation::Bicubic => {
+                let rows: Vec<f64> = (-1..=2)
+                    .map(|i| {
+                        let p = [-1, 0, 1, 2].map(|j| self.value(row + i, col + j));
+                        cubic(p, tx)
+                    })
+                    .collect();
+
+                cubic([rows[0], rows[1], rows[2], rows[3]], ty)
+            }
+        };
+
+        if n.is_nan() {
+            None
+        } else {
+            Some(n)
+        }
+    }
+}
+
+pub fn ellipsoidal2orthometric(
+    h: f64,
+    lat: f64,
+    lon: f64,
+    geoid: &dyn Geoid,
+    deg: bool,
+) -> Option<f64> {
+    Some(h - geoid.undulation(lat, lon, deg)?)
+}
+
+pub fn orthometric2ellipsoidal(
+    h: f64,
+    lat: f64,
+    lon: f64,
+    geoid: &dyn Geoid,
+    deg: bool,
+) -> Option<f64> {
+    Some(h + geoid.undulation(lat, lon, deg)?)
+}
+
+pub fn geodetic2ecef_orthometric(
+    lat: f64,
+    lon: f64,
+    h: f64,
+    geoid: &dyn Geoid,
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64, f64)> {
+    let alt = orthometric2ellipsoidal(h, lat, lon, geoid, deg)?;
+
+    geodetic2ecef(lat, lon, alt, ell, deg)
+}
+
+pub fn ecef2geodetic_orthometric(
+    x: f64,
+    y: f64,
+    z: f64,
+    geoid: &dyn Geoid,
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64, f64)> {
+    let (lat, lon, alt) = ecef2geodetic(x, y, z, ell, deg);
+    let h = ellipsoidal2orthometric(alt, lat, lon, geoid, deg)?;
+
+    Some((lat, lon, h))
+}
+
+pub fn geodetic2enu_orthometric(
+    lat: f64,
+    lon: f64,
+    h: f64,
+    lat0: f64,
+    lon0: f64,
+    h0: f64,
+    geoid: &dyn Geoid,
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64, f64)> {
+    let alt = orthometric2ellipsoidal(h, lat, lon, geoid, deg)?;
+    let alt0 = orthometric2ellipsoidal(h0, lat0, lon0, geoid, deg)?;
+
+    geodetic2enu(lat, lon, alt, lat0, lon0, alt0, ell, deg)
+}
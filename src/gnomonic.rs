@@ -0,0 +1,258 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::tmerc::wrap_lon;
+use crate::{vdist, vreckon, Ellipsoid};
+
+const C1: [[f64; 4]; 6] = [
+    [-1., 6., -16., 32.],
+    [-9., 64., -128., 2048.],
+    [0., 9., -16., 768.],
+    [0., 3., -5., 512.],
+    [0., 0., -7., 1280.],
+    [0., 0., -7., 2048.],
+];
+
+const C2: [[f64; 4]; 6] = [
+    [1., 2., 16., 32.],
+    [35., 64., 384., 2048.],
+    [0., 15., 80., 768.],
+    [0., 7., 35., 512.],
+    [0., 0., 63., 1280.],
+    [0., 0., 77., 2048.],
+];
+
+fn series(coeff: &[[f64; 4]; 6], eps: f64) -> [f64; 6] {
+    let eps2 = eps.powi(2);
+    let mut c = [0.; 6];
+    let mut d = eps;
+
+    for (l, row) in coeff.iter().enumerate() {
+        c[l] = d * ((row[0] * eps2 + row[1]) * eps2 + row[2]) / row[3];
+        d *= eps;
+    }
+
+    c
+}
+
+fn sin_series(sigma: f64, c: &[f64; 6]) -> f64 {
+    c.iter()
+        .enumerate()
+        .map(|(l, c)| c * (2. * (l + 1) as f64 * sigma).sin())
+        .sum()
+}
+
+// reduced length m12 and geodesic scales M12, M21 of the geodesic from (lat1, azi1) of length s12
+fn geodesic_scales(lat1: f64, azi1: f64, s12: f64, ell: &Ellipsoid) -> (f64, f64, f64) {
+    let f = ell.flattening;
+    let b = ell.semiminor_axis;
+    let ep2 = (ell.semimajor_axis.powi(2) - b.powi(2)) / b.powi(2);
+
+    let beta1 = ((1. - f) * lat1.tan()).atan();
+    let sin_alpha0 = azi1.sin() * beta1.cos();
+    let cos_alpha0_2 = 1. - sin_alpha0.powi(2);
+
+    let sigma1 = beta1.sin().atan2(azi1.cos() * beta1.cos());
+
+    let k2 = ep2 * cos_alpha0_2;
+    let eps = k2 / (2. * (1. + (1. + k2).sqrt()) + k2);
+    let eps2 = eps.powi(2);
+
+    let a1m1 = (eps2 * ((eps2 + 4.) * eps2 + 64.) / 256. + eps) / (1. - eps);
+    let a2m1 = (eps2 * ((-11. * eps2 - 28.) * eps2 - 192.) / 256. - eps) / (1. + eps);
+    let c1 = series(&C1, eps);
+    let c2 = series(&C2, eps);
+
+    let tau = s12 / (b * (1. + a1m1)) + sigma1 + sin_series(sigma1, &c1);
+    let mut sigma2 = tau;
+
+    for _ in 0..20 {
+        let derivative = 1.
+            + c1.iter()
+                .enumerate()
+                .map(|(l, c)| 2. * (l + 1) as f64 * c * (2. * (l + 1) as f64 * sigma2).cos())
+                .sum::<f64>();
+        let delta = (sigma2 + sin_series(sigma2, &c1) - tau) / derivative;
+        sigma2 -= delta;
+
+        if delta.abs() < 1e-15 {
+            break;
+        }
+    }
+
+    let sigma12 = sigma2 - sigma1;
+    let j12 = (a1m1 - a2m1) * sigma12
+        + (1. + a1m1) * (sin_series(sigma2, &c1) - sin_series(sigma1, &c1))
+        - (1. + a2m1) * (sin_series(sigma2, &c2) - sin_series(sigma1, &c2));
+
+    let (ssig1, csig1) = sigma1.sin_cos();
+    let (ssig2, csig2) = sigma2.sin_cos();
+    let dn1 = (1. + k2 * ssig1.powi(2)).sqrt();
+    let dn2 = (1. + k2 * ssig2.powi(2)).sqrt();
+
+    let cbet1 = beta1.cos();
+    let cbet2 = (sin_alpha0.powi(2) + cos_alpha0_2 * csig2.powi(2)).sqrt();
+
+    let m12 = b * (dn2 * csig1 * ssig2 - dn1 * ssig1 * csig2 - csig1 * csig2 * j12);
+
+    let csig12 = csig1 * csig2 + ssig1 * ssig2;
+    let t = ep2 * (cbet1 - cbet2) * (cbet1 + cbet2) / (dn1 + dn2);
+    let big_m12 = csig12 + (t * ssig2 - csig2 * j12) * ssig1 / dn1;
+    let big_m21 = csig12 - (t * ssig1 - csig1 * j12) * ssig2 / dn2;
+
+    (m12, big_m12, big_m21)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gnomonic {
+    pub lat0: f64,
+    pub lon0: f64,
+    pub ellipsoid: Ellipsoid,
+}
+
+impl Gnomonic {
+    pub fn new(lat0: f64, lon0: f64, ell: &Ellipsoid, deg: bool) -> Option<Self> {
+        let lat0 = if deg { lat0.to_radians() } else { lat0 };
+        let lon0 = if deg { lon0.to_radians() } else { lon0 };
+
+        if lat0.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        Some(Gnomonic {
+            lat0,
+            lon0,
+            ellipsoid: ell.clone(),
+        })
+    }
+
+    pub fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        if lat.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        let (s12, azi1) = vdist(self.lat0, self.lon0, lat, lon, &self.ellipsoid, false)?;
+
+        if s12 == 0. {
+            return Some((0., 0.));
+        }
+
+        let (m12, big_m12, _) = geodesic_scales(self.lat0, azi1, s12, &self.ellipsoid);
+
+        if big_m12 <= 0. {
+            return None;
+        }
+
+        let rho = m12 / big_m12;
+
+        Some((rho * azi1.sin(), rho * azi1.cos()))
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)> {
+        let a = self.ellipsoid.semimajor_axis;
+        let ell = &self.ellipsoid;
+
+        let azi0 = x.atan2(y);
+        let mut rho = x.hypot(y);
+        let mut s = a * (rho / a).atan();
+
+        let little = rho <= a;
+
+        if !little {
+            rho = 1. / rho;
+        }
+
+        let mut converged = rho == 0.;
+
+        for _ in 0..20 {
+            if converged {
+                break;
+            }
+
+            let (m, big_m, _) = geodesic_scales(self.lat0, azi0, s, ell);
+
+            let ds = if little {
+                (m / big_m - rho) * big_m.powi(2)
+            } else {
+                (rho - big_m / m) * m.powi(2)
+            };
+
+            s -= ds;
+            converged = ds.abs() <= 1e-12 * a;
+        }
+
+        if !converged {
+            return None;
+        }
+
+        let (lat, lon) = if s == 0. {
+            (self.lat0, self.lon0)
+        } else {
+            let (lat, lon) = vreckon(self.lat0, self.lon0, s, azi0, ell, false)?;
+            (lat, wrap_lon(lon))
+        };
+
+        let lat = if deg { lat.to_degrees() } else { lat };
+        let lon = if deg { lon.to_degrees() } else { lon };
+
+        Some((lat, lon))
+    }
+}
+
+pub fn geodesic_nearest(
+    lat1: f64,
+    lon1: f64,
+    lat2: f64,
+    lon2: f64,
+    lat: f64,
+    lon: f64,
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64, f64)> {
+    let lat1 = if deg { lat1.to_radians() } else { lat1 };
+    let lon1 = if deg { lon1.to_radians() } else { lon1 };
+    let lat2 = if deg { lat2.to_radians() } else { lat2 };
+    let lon2 = if deg { lon2.to_radians() } else { lon2 };
+    let lat = if deg { lat.to_radians() } else { lat };
+    let lon = if deg { lon.to_radians() } else { lon };
+
+    let mut latc = (lat1 + lat2) / 2.;
+    let mut lonc = lon1 + wrap_lon(lon2 - lon1) / 2.;
+
+    let mut converged = false;
+
+    for _ in 0..20 {
+        let gnomonic = Gnomonic::new(latc, lonc, ell, false)?;
+
+        let (xa, ya) = gnomonic.forward(lat1, lon1, false)?;
+        let (xb, yb) = gnomonic.forward(lat2, lon2, false)?;
+        let (xp, yp) = gnomonic.forward(lat, lon, false)?;
+
+        let (dx, dy) = (xb - xa, yb - ya);
+        let t = (((xp - xa) * dx + (yp - ya) * dy) / (dx.powi(2) + dy.powi(2))).clamp(0., 1.);
+
+        let (latn, lonn) = gnomonic.inverse(xa + t * dx, ya + t * dy, false)?;
+        let moved = vdist(latc, lonc, latn, lonn, ell, false)?.0;
+
+        latc = latn;
+        lonc = lonn;
+
+        if moved < 1e-6 {
+            converged = true;
+            break;
+        }
+    }
+
+    if !converged {
+        return None;
+    }
+
+    let distance = vdist(latc, lonc, lat, lon, ell, false)?.0;
+
+    let latc = if deg { latc.to_degrees() } else { latc };
+    let lonc = if deg { lonc.to_degrees() } else { lonc };
+
+    Some((latc, lonc, distance))
+}
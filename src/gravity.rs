@@ -0,0 +1,195 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::{ecef2enuv, geodetic2ecef, Ellipsoid};
+
+struct NormalField {
+    gm: f64,
+    omega: f64,
+    a: f64,
+    e: f64,
+    q0: f64,
+}
+
+impl NormalField {
+    fn new(ell: &Ellipsoid) -> Option<Self> {
+        let physical = ell.physical?;
+
+        let a = ell.semimajor_axis;
+        let b = ell.semiminor_axis;
+        let e = (a.powi(2) - b.powi(2)).sqrt();
+
+        if e == 0. {
+            return None;
+        }
+
+        let ep = e / b;
+
+        Some(NormalField {
+            gm: physical.gm,
+            omega: physical.angular_velocity,
+            a,
+            e,
+            q0: 0.5 * ((1. + 3. / ep.powi(2)) * ep.atan() - 3. / ep),
+        })
+    }
+
+    fn q(&self, u: f64) -> f64 {
+        let ratio = u / self.e;
+
+        0.5 * ((1. + 3. * ratio.powi(2)) * (1. / ratio).atan() - 3. * ratio)
+    }
+
+    fn qp(&self, u: f64) -> f64 {
+        let ratio = u / self.e;
+
+        3. * (1. + ratio.powi(2)) * (1. - ratio * (1. / ratio).atan()) - 1.
+    }
+
+    fn coordinates(&self, x: f64, y: f64, z: f64) -> (f64, f64) {
+        let p = x.hypot(y);
+        let r2 = x.powi(2) + y.powi(2) + z.powi(2) - self.e.powi(2);
+        let u = (0.5 * (r2 + r2.hypot(2. * self.e * z))).sqrt();
+        let beta = (z * u.hypot(self.e)).atan2(u * p);
+
+        (u, beta)
+    }
+
+    fn potential(&self, u: f64, beta: f64) -> f64 {
+        let w2 = self.omega.powi(2);
+
+        self.gm / self.e * (self.e / u).atan()
+            + 0.5 * w2 * self.a.powi(2) * self.q(u) / self.q0 * (beta.sin().powi(2) - 1. / 3.)
+            + 0.5 * w2 * (u.powi(2) + self.e.powi(2)) * beta.cos().powi(2)
+    }
+
+    fn components(&self, u: f64, beta: f64) -> (f64, f64) {
+        let w2 = self.omega.powi(2);
+        let ue2 = u.powi(2) + self.e.powi(2);
+        let w = ((u.powi(2) + (self.e * beta.sin()).powi(2)) / ue2).sqrt();
+
+        let gamma_u = -(self.gm / ue2
+            + w2 * self.a.powi(2) * self.e / ue2 * self.qp(u) / self.q0
+                * (0.5 * beta.sin().powi(2) - 1. / 6.)
+            - w2 * u * beta.cos().powi(2))
+            / w;
+        let gamma_beta = (w2 * self.a.powi(2) / ue2.sqrt() * self.q(u) / self.q0 - w2 * ue2.sqrt())
+            * beta.sin()
+            * beta.cos()
+            / w;
+
+        (gamma_u, gamma_beta)
+    }
+}
+
+pub fn somigliana(lat: f64, ell: &Ellipsoid, deg: bool) -> Option<f64> {
+    let lat = if deg { lat.to_radians() } else { lat };
+
+    if lat.abs() > FRAC_PI_2 {
+        return None;
+    }
+
+    let (gamma_e, gamma_p) = (equatorial_gravity(ell)?, polar_gravity(ell)?);
+
+    let a = ell.semimajor_axis;
+    let b = ell.semiminor_axis;
+    let k = b * gamma_p / (a * gamma_e) - 1.;
+    let sin2 = lat.sin().powi(2);
+
+    Some(gamma_e * (1. + k * sin2) / (1. - ell.eccentricity.powi(2) * sin2).sqrt())
+}
+
+pub fn equatorial_gravity(ell: &Ellipsoid) -> Option<f64> {
+    let field = NormalField::new(ell)?;
+    let b = ell.semiminor_axis;
+    let m = field.omega.powi(2) * field.a.powi(2) * b / field.gm;
+    let ep = field.e / b;
+
+    Some(field.gm / (field.a * b) * (1. - m - m * ep * field.qp(b) / (6. * field.q0)))
+}
+
+pub fn polar_gravity(ell: &Ellipsoid) -> Option<f64> {
+    let field = NormalField::new(ell)?;
+    let b = ell.semiminor_axis;
+    let m = field.omega.powi(2) * field.a.powi(2) * b / field.gm;
+    let ep = field.e / b;
+
+    Some(field.gm / field.a.powi(2) * (1. + m * ep * field.qp(b) / (3. * field.q0)))
+}
+
+pub fn zonal_harmonic(degree: usize, ell: &Ellipsoid) -> Option<f64> {
+    let j2 = ell.physical?.j2;
+    let e2 = ell.eccentricity.powi(2);
+
+    if degree == 0 || degree % 2 == 1 || e2 == 0. {
+        return None;
+    }
+
+    let n = degree / 2;
+    let nf = n as f64;
+    let sign = if n % 2 == 1 { 1. } else { -1. };
+
+    Some(
+        sign * 3. * e2.powi(n as i32) / ((2. * nf + 1.) * (2. * nf + 3.))
+            * (1. - nf + 5. * nf * j2 / e2),
+    )
+}
+
+pub fn normal_potential(lat: f64, h: f64, ell: &Ellipsoid, deg: bool) -> Option<f64> {
+    let field = NormalField::new(ell)?;
+    let (x, y, z) = geodetic2ecef(lat, 0., h, ell, deg)?;
+    let (u, beta) = field.coordinates(x, y, z);
+
+    Some(field.potential(u, beta))
+}
+
+pub fn normal_gravity(lat: f64, h: f64, ell: &Ellipsoid, deg: bool) -> Option<f64> {
+    let field = NormalField::new(ell)?;
+    let (x, y, z) = geodetic2ecef(lat, 0., h, ell, deg)?;
+    let (u, beta) = field.coordinates(x, y, z);
+    let (gamma_u, gamma_beta) = field.components(u, beta);
+
+    Some(gamma_u.hypot(gamma_beta))
+}
+
+pub fn gravity_ecef(x: f64, y: f64, z: f64, ell: &Ellipsoid) -> Option<(f64, f64, f64)> {
+    let field = NormalField::new(ell)?;
+    let (u, beta) = field.coordinates(x, y, z);
+    let (gamma_u, gamma_beta) = field.components(u, beta);
+
+    let lon = y.atan2(x);
+    let ue = u.hypot(field.e);
+    let norm = (u.powi(2) + (field.e * beta.sin()).powi(2)).sqrt();
+
+    let (sin_beta, cos_beta) = beta.sin_cos();
+    let (sin_lon, cos_lon) = lon.sin_cos();
+
+    let radial = (gamma_u * u * cos_beta - gamma_beta * ue * sin_beta) / norm;
+    let gz = (gamma_u * ue * sin_beta + gamma_beta * u * cos_beta) / norm;
+
+    Some((radial * cos_lon, radial * sin_lon, gz))
+}
+
+pub fn gravity_enu(
+    lat: f64,
+    lon: f64,
+    h: f64,
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64, f64)> {
+    let (x, y, z) = geodetic2ecef(lat, lon, h, ell, deg)?;
+    let (gx, gy, gz) = gravity_ecef(x, y, z, ell)?;
+
+    Some(ecef2enuv(gx, gy, gz, lat, lon, deg))
+}
+
+pub fn gravity_ned(
+    lat: f64,
+    lon: f64,
+    h: f64,
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64, f64)> {
+    let (e, n, u) = gravity_enu(lat, lon, h, ell, deg)?;
+
+    Some((n, e, -u))
+}
@@ -0,0 +1,223 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::{ecef2geodetic, geodetic2ecef, geodetic2enu, somigliana, Ellipsoid, Geoid};
+
+const HELMERT: f64 = 0.424e-6;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeightKind {
+    Ellipsoidal,
+    Orthometric,
+    Normal,
+    Geopotential,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Height {
+    Ellipsoidal(f64),
+    Orthometric(f64),
+    Normal(f64),
+    Geopotential(f64),
+}
+
+impl Height {
+    pub fn new(value: f64, kind: HeightKind) -> Self {
+        match kind {
+            HeightKind::Ellipsoidal => Height::Ellipsoidal(value),
+            HeightKind::Orthometric => Height::Orthometric(value),
+            HeightKind::Normal => Height::Normal(value),
+            HeightKind::Geopotential => Height::Geopotential(value),
+        }
+    }
+
+    pub fn kind(&self) -> HeightKind {
+        match self {
+            Height::Ellipsoidal(_) => HeightKind::Ellipsoidal,
+            Height::Orthometric(_) => HeightKind::Orthometric,
+            Height::Normal(_) => HeightKind::Normal,
+            Height::Geopotential(_) => HeightKind::Geopotential,
+        }
+    }
+
+    pub fn value(&self) -> f64 {
+        match *self {
+            Height::Ellipsoidal(h)
+            | Height::Orthometric(h)
+            | Height::Normal(h)
+            | Height::Geopotential(h) => h,
+        }
+    }
+
+    pub fn checked_add(&self, other: &Height) -> Option<Height> {
+        if self.kind() != other.kind() {
+            return None;
+        }
+
+        Some(Height::new(self.value() + other.value(), self.kind()))
+    }
+
+    pub fn difference(&self, other: &Height) -> Option<f64> {
+        if self.kind() != other.kind() {
+            return None;
+        }
+
+        Some(self.value() - other.value())
+    }
+}
+
+pub fn geopotential2normal(c: f64, lat: f64, ell: &Ellipsoid, deg: bool) -> Option<f64> {
+    let mut h = c / somigliana(lat, ell, deg)?;
+
+    for _ in 0..5 {
+        h = c / mean_normal_gravity(lat, h, ell, deg)?;
+    }
+
+    Some(h)
+}
+
+pub fn normal2geopotential(h: f64, lat: f64, ell: &Ellipsoid, deg: bool) -> Option<f64> {
+    Some(h * mean_normal_gravity(lat, h, ell, deg)?)
+}
+
+pub fn geopotential2orthometric(c: f64, gravity: f64) -> f64 {
+    2. * c / (gravity + (gravity.powi(2) + 4. * HELMERT * c).sqrt())
+}
+
+pub fn orthometric2geopotential(h: f64, gravity: f64) -> f64 {
+    h * (gravity + HELMERT * h)
+}
+
+fn mean_normal_gravity(lat: f64, h: f64, ell: &Ellipsoid, deg: bool) -> Option<f64> {
+    let lat = if deg { lat.to_radians() } else { lat };
+
+    if lat.abs() > FRAC_PI_2 {
+        return None;
+    }
+
+    let physical = ell.physical?;
+    let a = ell.semimajor_axis;
+    let f = ell.flattening;
+    let m = physical.angular_velocity.powi(2) * a.powi(2) * ell.semiminor_axis / physical.gm;
+
+    let gamma = somigliana(lat, ell, false)?;
+
+    Some(gamma * (1. - (1. + f + m - 2. * f * lat.sin().powi(2)) * h / a + (h / a).powi(2)))
+}
+
+pub struct VerticalDatum<'a> {
+    pub ellipsoid: &'a Ellipsoid,
+    pub geoid: Option<&'a dyn Geoid>,
+    pub quasigeoid: Option<&'a dyn Geoid>,
+}
+
+impl<'a> VerticalDatum<'a> {
+    pub fn new(ellipsoid: &'a Ellipsoid) -> Self {
+        VerticalDatum {
+            ellipsoid,
+            geoid: None,
+            quasigeoid: None,
+        }
+    }
+
+    pub fn with_geoid(mut self, geoid: &'a dyn Geoid) -> Self {
+        self.geoid = Some(geoid);
+        self
+    }
+
+    pub fn with_quasigeoid(mut self, quasigeoid: &'a dyn Geoid) -> Self {
+        self.quasigeoid = Some(quasigeoid);
+        self
+    }
+
+    pub fn ellipsoidal(&self, height: Height, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+        match height {
+            Height::Ellipsoidal(h) => Some(h),
+            Height::Orthometric(h) => Some(h + self.geoid?.undulation(lat, lon, deg)?),
+            Height::Normal(h) => Some(h + self.quasigeoid?.undulation(lat, lon, deg)?),
+            Height::Geopotential(c) => {
+                let h = geopotential2normal(c, lat, self.ellipsoid, deg)?;
+
+                self.ellipsoidal(Height::Normal(h), lat, lon, deg)
+            }
+        }
+    }
+
+    pub fn convert(
+        &self,
+        height: Height,
+        kind: HeightKind,
+        lat: f64,
+        lon: f64,
+        deg: bool,
+    ) -> Option<Height> {
+        if height.kind() == kind {
+            return Some(height);
+        }
+
+        match (height, kind) {
+            (Height::Normal(h), HeightKind::Geopotential) => Some(Height::Geopotential(
+                normal2geopotential(h, lat, self.ellipsoid, deg)?,
+            )),
+            (Height::Geopotential(c), HeightKind::Normal) => Some(Height::Normal(
+                geopotential2normal(c, lat, self.ellipsoid, deg)?,
+            )),
+            _ => {
+                let h = self.ellipsoidal(height, lat, lon, deg)?;
+
+                let value = match kind {
+                    HeightKind::Ellipsoidal => h,
+                    HeightKind::Orthometric => h - self.geoid?.undulation(lat, lon, deg)?,
+                    HeightKind::Normal => h - self.quasigeoid?.undulation(lat, lon, deg)?,
+                    HeightKind::Geopotential => {
+                        let normal = h - self.quasigeoid?.undulation(lat, lon, deg)?;
+                        normal2geopotential(normal, lat, self.ellipsoid, deg)?
+                    }
+                };
+
+                Some(Height::new(value, kind))
+            }
+        }
+    }
+}
+
+pub fn geodetic2ecef_height(
+    lat: f64,
+    lon: f64,
+    height: Height,
+    datum: &VerticalDatum,
+    deg: bool,
+) -> Option<(f64, f64, f64)> {
+    let alt = datum.ellipsoidal(height, lat, lon, deg)?;
+
+    geodetic2ecef(lat, lon, alt, datum.ellipsoid, deg)
+}
+
+pub fn ecef2geodetic_height(
+    x: f64,
+    y: f64,
+    z: f64,
+    kind: HeightKind,
+    datum: &VerticalDatum,
+    deg: bool,
+) -> Option<(f64, f64, Height)> {
+    let (lat, lon, alt) = ecef2geodetic(x, y, z, datum.ellipsoid, deg);
+    let height = datum.convert(Height::Ellipsoidal(alt), kind, lat, lon, deg)?;
+
+    Some((lat, lon, height))
+}
+
+pub fn geodetic2enu_height(
+    lat: f64,
+    lon: f64,
+    height: Height,
+    lat0: f64,
+    lon0: f64,
+    height0: Height,
+    datum: &VerticalDatum,
+    deg: bool,
+) -> Option<(f64, f64, f64)> {
+    let alt = datum.ellipsoidal(height, lat, lon, deg)?;
+    let alt0 = datum.ellipsoidal(height0, lat0, lon0, deg)?;
+
+    geodetic2enu(lat, lon, alt, lat0, lon0, alt0, datum.ellipsoid, deg)
+}
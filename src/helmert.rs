@@ -0,0 +1,81 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Helmert {
+    pub tx: f64,
+    pub ty: f64,
+    pub tz: f64,
+    pub rx: f64,
+    pub ry: f64,
+    pub rz: f64,
+    pub scale: f64,
+}
+
+const ARCSEC: f64 = std::f64::consts::PI / 648_000.;
+
+impl Helmert {
+    pub fn new(tx: f64, ty: f64, tz: f64, rx: f64, ry: f64, rz: f64, scale: f64) -> Self {
+        Helmert {
+            tx,
+            ty,
+            tz,
+            rx,
+            ry,
+            rz,
+            scale,
+        }
+    }
+
+    pub fn translation(tx: f64, ty: f64, tz: f64) -> Self {
+        Self::new(tx, ty, tz, 0., 0., 0., 0.)
+    }
+
+    pub fn from_towgs84(params: &[f64]) -> Option<Self> {
+        match params.len() {
+            3 => Some(Self::translation(params[0], params[1], params[2])),
+            7 => Some(Self::new(
+                params[0], params[1], params[2], params[3], params[4], params[5], params[6],
+            )),
+            _ => None,
+        }
+    }
+
+    fn matrix(&self) -> [[f64; 3]; 3] {
+        let m = 1. + self.scale * 1e-6;
+        let (rx, ry, rz) = (self.rx * ARCSEC, self.ry * ARCSEC, self.rz * ARCSEC);
+
+        [
+            [m, -m * rz, m * ry],
+            [m * rz, m, -m * rx],
+            [-m * ry, m * rx, m],
+        ]
+    }
+
+    pub fn forward(&self, x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+        let r = self.matrix();
+
+        (
+            self.tx + r[0][0] * x + r[0][1] * y + r[0][2] * z,
+            self.ty + r[1][0] * x + r[1][1] * y + r[1][2] * z,
+            self.tz + r[2][0] * x + r[2][1] * y + r[2][2] * z,
+        )
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+        let r = self.matrix();
+        let (x, y, z) = (x - self.tx, y - self.ty, z - self.tz);
+
+        let cofactor = |i: usize, j: usize| {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+
+            r[i1][j1] * r[i2][j2] - r[i1][j2] * r[i2][j1]
+        };
+
+        let det = r[0][0] * cofactor(0, 0) + r[0][1] * cofactor(0, 1) + r[0][2] * cofactor(0, 2);
+
+        (
+            (cofactor(0, 0) * x + cofactor(1, 0) * y + cofactor(2, 0) * z) / det,
+            (cofactor(0, 1) * x + cofactor(1, 1) * y + cofactor(2, 1) * z) / det,
+            (cofactor(0, 2) * x + cofactor(1, 2) * y + cofactor(2, 2) * z) / det,
+        )
+    }
+}
@@ -0,0 +1,688 @@
+use std::collections::BTreeSet;
+use std::f64::consts::PI;
+
+use crate::rsphere::authalic;
+use crate::{authalic2geodetic, cart2sph, geodetic2authalic, sph2cart, Ellipsoid};
+
+const MAX_CELLS: usize = 1 << 20;
+
+const UNITS: [Eisenstein; 6] = [
+    (1., 0.),
+    (0., 1.),
+    (-1., 1.),
+    (-1., 0.),
+    (0., -1.),
+    (1., -1.),
+];
+const CORNERS: [Eisenstein; 3] = [(0., 0.), (1., 0.), (0., 1.)];
+
+type Vector = (f64, f64, f64);
+type Eisenstein = (f64, f64);
+
+fn dot(a: Vector, b: Vector) -> f64 {
+    a.0 * b.0 + a.1 * b.1 + a.2 * b.2
+}
+
+fn cross(a: Vector, b: Vector) -> Vector {
+    (
+        a.1 * b.2 - a.2 * b.1,
+        a.2 * b.0 - a.0 * b.2,
+        a.0 * b.1 - a.1 * b.0,
+    )
+}
+
+fn normalize(a: Vector) -> Vector {
+    let norm = dot(a, a).sqrt();
+
+    (a.0 / norm, a.1 / norm, a.2 / norm)
+}
+
+fn add(a: Eisenstein, b: Eisenstein) -> Eisenstein {
+    (a.0 + b.0, a.1 + b.1)
+}
+
+fn sub(a: Eisenstein, b: Eisenstein) -> Eisenstein {
+    (a.0 - b.0, a.1 - b.1)
+}
+
+fn mul(a: Eisenstein, b: Eisenstein) -> Eisenstein {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0 + a.1 * b.1)
+}
+
+fn conj(a: Eisenstein) -> Eisenstein {
+    (a.0 + a.1, -a.1)
+}
+
+fn norm(a: Eisenstein) -> f64 {
+    a.0 * a.0 + a.0 * a.1 + a.1 * a.1
+}
+
+fn round(a: Eisenstein) -> Eisenstein {
+    let (x, z) = a;
+    let y = -x - z;
+
+    let (mut rx, ry, mut rz) = (x.round(), y.round(), z.round());
+    let (dx, dy, dz) = ((rx - x).abs(), (ry - y).abs(), (rz - z).abs());
+
+    if dx > dy && dx > dz {
+        rx = -ry - rz;
+    } else if dz >= dy {
+        rz = -rx - ry;
+    }
+
+    (rx, rz)
+}
+
+// Snyder (1992) equal-area projection of a spherical icosahedron face
+#[derive(Clone, Debug, PartialEq)]
+struct Snyder {
+    g: f64,
+    big_g: f64,
+    cot_theta: f64,
+    rprime: f64,
+}
+
+impl Snyder {
+    fn new() -> Self {
+        let big_g = PI / 5.;
+        let theta = PI / 6.;
+        let g = ((PI / 3.).tan().recip() / big_g.tan()).acos();
+        let rprime = (4. * PI / (15. * 3f64.sqrt() * g.tan().powi(2))).sqrt();
+
+        Snyder {
+            g,
+            big_g,
+            cot_theta: theta.tan().recip(),
+            rprime,
+        }
+    }
+
+    fn sector(azimuth: f64) -> (f64, f64) {
+        let sector = (azimuth / (2. * PI / 3.)).floor();
+
+        (azimuth - sector * 2. * PI / 3., sector * 2. * PI / 3.)
+    }
+
+    fn h(&self, az: f64) -> f64 {
+        (az.sin() * self.big_g.sin() * self.g.cos() - az.cos() * self.big_g.cos())
+            .clamp(-1., 1.)
+            .acos()
+    }
+
+    fn forward(&self, z: f64, azimuth: f64) -> (f64, f64) {
+        let (az, offset) = Self::sector(azimuth);
+        let tan_g = self.g.tan();
+
+        let q = tan_g.atan2(az.cos() + az.sin() * self.cot_theta);
+        let area = az + self.big_g + self.h(az) - PI;
+
+        let rtan_g = self.rprime * tan_g;
+        let azp = (2. * area).atan2(rtan_g.powi(2) - 2. * area * self.cot_theta);
+        let dp = rtan_g / (azp.cos() + azp.sin() * self.cot_theta);
+        let f = dp / (2. * self.rprime * (q / 2.).sin());
+        let rho = 2. * self.rprime * f * (z / 2.).sin();
+
+        let azp = azp + offset;
+
+        (rho * azp.sin(), rho * azp.cos())
+    }
+
+    fn inverse(&self, x: f64, y: f64) -> (f64, f64) {
+        let rho = x.hypot(y);
+
+        if rho == 0. {
+            return (0., 0.);
+        }
+
+        let (azp, offset) = Self::sector(x.atan2(y));
+        let tan_g = self.g.tan();
+        let rtan_g = self.rprime * tan_g;
+
+        let area = rtan_g.powi(2) * azp.sin() / (2. * (azp.cos() + azp.sin() * self.cot_theta));
+
+        let mut az = azp;
+
+        for _ in 0..20 {
+            let h = self.h(az);
+            let dx = az.cos() * self.big_g.sin() * self.g.cos() + az.sin() * self.big_g.cos();
+            let delta = (az + self.big_g + h - PI - area) / (1. - dx / h.sin());
+            az -= delta;
+
+            if delta.abs() < 1e-15 {
+                break;
+            }
+        }
+
+        let q = tan_g.atan2(az.cos() + az.sin() * self.cot_theta);
+        let dp = rtan_g / (azp.cos() + azp.sin() * self.cot_theta);
+        let z = 2. * (rho * (q / 2.).sin() / dp).min(1.).asin();
+
+        (z, az + offset)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Face {
+    vertices: [usize; 3],
+    centre: Vector,
+    ex: Vector,
+    ey: Vector,
+    across: [(usize, usize, usize); 3],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HexCell {
+    pub resolution: u8,
+    pub face: u8,
+    pub i: i64,
+    pub j: i64,
+}
+
+impl HexCell {
+    fn coords(&self) -> Eisenstein {
+        (self.i as f64, self.j as f64)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HexGrid {
+    pub aperture: u8,
+    pub ellipsoid: Ellipsoid,
+    vertices: Vec<Vector>,
+    faces: Vec<Face>,
+    snyder: Snyder,
+}
+
+impl HexGrid {
+    pub fn new(aperture: u8, ell: &Ellipsoid) -> Option<Self> {
+        if aperture != 3 && aperture != 7 {
+            return None;
+        }
+
+        let lat = 0.5f64.atan();
+        let mut vertices = vec![(0., 0., 1.)];
+
+        for k in 0..5 {
+            vertices.push(sph2cart(2. * PI * k as f64 / 5., lat, 1.));
+        }
+
+        for k in 0..5 {
+            vertices.push(sph2cart(2. * PI * (k as f64 + 0.5) / 5., -lat, 1.));
+        }
+
+        vertices.push((0., 0., -1.));
+
+        let mut triples = Vec::new();
+
+        for k in 0..5 {
+            let (next, below, below_next) = ((k + 1) % 5 + 1, k + 6, (k + 1) % 5 + 6);
+
+            triples.push([0, k + 1, next]);
+            triples.push([k + 1, below, next]);
+            triples.push([next, below, below_next]);
+            triples.push([11, below_next, below]);
+        }
+
+        let mut faces: Vec<Face> = triples
+            .into_iter()
+            .map(|[a, b, c]| {
+                let (va, vb, vc) = (vertices[a], vertices[b], vertices[c]);
+                let centre =
+                    normalize((va.0 + vb.0 + vc.0, va.1 + vb.1 + vc.1, va.2 + vb.2 + vc.2));
+
+                let ab = (vb.0 - va.0, vb.1 - va.1, vb.2 - va.2);
+                let ac = (vc.0 - va.0, vc.1 - va.1, vc.2 - va.2);
+
+                let vertices = if dot(cross(ab, ac), centre) > 0. {
+                    [a, b, c]
+                } else {
+                    [a, c, b]
+                };
+
+                let d = dot(va, centre);
+                let ey = normalize((
+                    va.0 - d * centre.0,
+                    va.1 - d * centre.1,
+                    va.2 - d * centre.2,
+                ));
+
+                Face {
+                    vertices,
+                    centre,
+                    ex: cross(ey, centre),
+                    ey,
+                    across: [(0, 0, 0); 3],
+                }
+            })
+            .collect();
+
+        for f in 0..faces.len() {
+            for k in 0..3 {
+                let (a, b) = (faces[f].vertices[k], faces[f].vertices[(k + 1) % 3]);
+
+                let (g, face) = faces.iter().enumerate().find(|(g, face)| {
+                    *g != f && face.vertices.contains(&a) && face.vertices.contains(&b)
+                })?;
+
+                let pa = face.vertices.iter().position(|&v| v == a)?;
+                let pb = face.vertices.iter().position(|&v| v == b)?;
+
+                faces[f].across[k] = (g, pa, pb);
+            }
+        }
+
+        Some(HexGrid {
+            aperture,
+            ellipsoid: ell.clone(),
+            vertices,
+            faces,
+            snyder: Snyder::new(),
+        })
+    }
+
+    pub fn max_resolution(&self) -> u8 {
+        if self.aperture == 3 {
+            30
+        } else {
+            15
+        }
+    }
+
+    fn generator(&self) -> Eisenstein {
+        if self.aperture == 3 {
+            (1., 1.)
+        } else {
+            (2., 1.)
+        }
+    }
+
+    fn scale(&self, resolution: u8) -> Eisenstein {
+        (0..resolution).fold((1., 0.), |z, _| mul(z, self.generator()))
+    }
+
+    pub fn cell_count(&self, resolution: u8) -> u64 {
+        10 * (self.aperture as u64).pow(resolution as u32) + 2
+    }
+
+    pub fn cell_area(&self, resolution: u8) -> f64 {
+        4. * PI * authalic(&self.ellipsoid).powi(2)
+            / (10. * (self.aperture as f64).powi(resolution as i32))
+    }
+
+    fn barycentric(w: Eisenstein, z: Eisenstein) -> [f64; 3] {
+        let (s, t) = mul(w, conj(z));
+        let n = norm(z);
+
+        [n - s - t, s, t]
+    }
+
+    fn cross_edge(
+        &self,
+        face: usize,
+        edge: usize,
+        w: Eisenstein,
+        z: Eisenstein,
+    ) -> (usize, Eisenstein) {
+        let (i, j) = (edge, (edge + 1) % 3);
+        let (g, pi, pj) = self.faces[face].across[edge];
+
+        let unit = mul(
+            sub(CORNERS[pj], CORNERS[pi]),
+            conj(sub(CORNERS[j], CORNERS[i])),
+        );
+        let w = add(mul(CORNERS[pi], z), mul(sub(w, mul(CORNERS[i], z)), unit));
+
+        (g, w)
+    }
+
+    fn locate(&self, mut face: usize, mut w: Eisenstein, z: Eisenstein) -> (usize, Eisenstein) {
+        for _ in 0..3 {
+            let lambda = Self::barycentric(w, z);
+            let (k, min) = (0..3)
+                .map(|k| (k, lambda[k]))
+                .fold((0, f64::INFINITY), |a, b| if b.1 < a.1 { b } else { a });
+
+            if min >= -1e-9 * norm(z) {
+                break;
+            }
+
+            (face, w) = self.cross_edge(face, (k + 1) % 3, w, z);
+        }
+
+        (face, w)
+    }
+
+    fn canonical(&self, face: usize, w: Eisenstein, resolution: u8) -> HexCell {
+        let z = self.scale(resolution);
+        let (face, w) = self.locate(face, w, z);
+        let lambda = Self::barycentric(w, z);
+
+        let zeros: Vec<usize> = (0..3).filter(|&k| lambda[k] == 0.).collect();
+
+        let (face, w) = match zeros.len() {
+            2 => {
+                let corner = (0..3).find(|k| !zeros.contains(k)).unwrap();
+                let vertex = self.faces[face].vertices[corner];
+
+                let (g, face) = self
+                    .faces
+                    .iter()
+                    .enumerate()
+                    .find(|(_, f)| f.vertices.contains(&vertex))
+                    .unwrap();
+                let corner = face.vertices.iter().position(|&v| v == vertex).unwrap();
+
+                (g, mul(CORNERS[corner], z))
+            }
+            1 => {
+                let other = self.cross_edge(face, (zeros[0] + 1) % 3, w, z);
+
+                if other.0 < face {
+                    other
+                } else {
+                    (face, w)
+                }
+            }
+            _ => (face, w),
+        };
+
+        HexCell {
+            resolution,
+            face: face as u8,
+            i: w.0 as i64,
+            j: w.1 as i64,
+        }
+    }
+
+    fn valid(&self, cell: &HexCell) -> bool {
+        cell.resolution <= self.max_resolution()
+            && (cell.face as usize) < self.faces.len()
+            && self.canonical(cell.face as usize, cell.coords(), cell.resolution) == *cell
+    }
+
+    fn vertex(&self, cell: &HexCell) -> Option<usize> {
+        let z = self.scale(cell.resolution);
+
+        (0..3)
+            .find(|&k| mul(CORNERS[k], z) == cell.coords())
+            .map(|k| self.faces[cell.face as usize].vertices[k])
+    }
+
+    pub fn is_pentagon(&self, cell: &HexCell) -> bool {
+        self.vertex(cell).is_some()
+    }
+
+    fn corners(&self) -> ((f64, f64), (f64, f64)) {
+        let radius = self.snyder.rprime * self.snyder.g.tan();
+
+        ((0., radius), (-radius * (PI / 3.).sin(), -radius / 2.))
+    }
+
+    fn lattice_to_plane(&self, w: Eisenstein, z: Eisenstein) -> (f64, f64) {
+        let (p0, p1) = self.corners();
+        let (dx, dy) = (p1.0 - p0.0, p1.1 - p0.1);
+
+        let n = norm(z);
+        let t = mul(w, conj(z));
+        let (tx, ty) = ((t.0 + t.1 / 2.) / n, t.1 * 3f64.sqrt() / 2. / n);
+
+        (p0.0 + tx * dx - ty * dy, p0.1 + tx * dy + ty * dx)
+    }
+
+    fn plane_to_lattice(&self, x: f64, y: f64, z: Eisenstein) -> Eisenstein {
+        let (p0, p1) = self.corners();
+        let (dx, dy) = (p1.0 - p0.0, p1.1 - p0.1);
+        let d2 = dx * dx + dy * dy;
+
+        let (rx, ry) = (x - p0.0, y - p0.1);
+        let (tx, ty) = ((rx * dx + ry * dy) / d2, (ry * dx - rx * dy) / d2);
+
+        let zc = (z.0 + z.1 / 2., z.1 * 3f64.sqrt() / 2.);
+        let (wx, wy) = (tx * zc.0 - ty * zc.1, tx * zc.1 + ty * zc.0);
+
+        let b = wy * 2. / 3f64.sqrt();
+
+        (wx - b / 2., b)
+    }
+
+    fn lattice_to_sphere(&self, face: usize, w: Eisenstein, z: Eisenstein) -> Vector {
+        let (x, y) = self.lattice_to_plane(w, z);
+        let (dist, azimuth) = self.snyder.inverse(x, y);
+
+        let f = &self.faces[face];
+        let (sz, cz) = dist.sin_cos();
+        let (sa, ca) = azimuth.sin_cos();
+
+        normalize((
+            cz * f.centre.0 + sz * (ca * f.ey.0 + sa * f.ex.0),
+            cz * f.centre.1 + sz * (ca * f.ey.1 + sa * f.ex.1),
+            cz * f.centre.2 + sz * (ca * f.ey.2 + sa * f.ex.2),
+        ))
+    }
+
+    fn to_geodetic(&self, p: Vector, deg: bool) -> Option<(f64, f64)> {
+        let (lon, beta, _) = cart2sph(p.0, p.1, p.2);
+        let lat = authalic2geodetic(beta, &self.ellipsoid, false)?;
+
+        if deg {
+            Some((lat.to_degrees(), lon.to_degrees()))
+        } else {
+            Some((lat, lon))
+        }
+    }
+
+    pub fn cell(&self, lat: f64, lon: f64, resolution: u8, deg: bool) -> Option<HexCell> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        if resolution > self.max_resolution() {
+            return None;
+        }
+
+        let beta = geodetic2authalic(lat, &self.ellipsoid, false)?;
+        let p = sph2cart(lon, beta, 1.);
+
+        let face = (0..self.faces.len()).max_by(|&a, &b| {
+            dot(p, self.faces[a].centre).total_cmp(&dot(p, self.faces[b].centre))
+        })?;
+        let f = &self.faces[face];
+
+        let dist = dot(p, f.centre).clamp(-1., 1.).acos();
+        let azimuth = dot(p, f.ex).atan2(dot(p, f.ey));
+        let (x, y) = self.snyder.forward(dist, azimuth);
+
+        let z = self.scale(resolution);
+        let w = round(self.plane_to_lattice(x, y, z));
+
+        Some(self.canonical(face, w, resolution))
+    }
+
+    pub fn centre(&self, cell: &HexCell, deg: bool) -> Option<(f64, f64)> {
+        if !self.valid(cell) {
+            return None;
+        }
+
+        let z = self.scale(cell.resolution);
+
+        self.to_geodetic(
+            self.lattice_to_sphere(cell.face as usize, cell.coords(), z),
+            deg,
+        )
+    }
+
+    pub fn boundary(&self, cell: &HexCell, deg: bool) -> Option<Vec<(f64, f64)>> {
+        if !self.valid(cell) {
+            return None;
+        }
+
+        let z = self.scale(cell.resolution);
+        let offsets: Vec<Eisenstein> = (0..6)
+            .map(|k| {
+                let (a, b) = (UNITS[k], UNITS[(k + 1) % 6]);
+                ((a.0 + b.0) / 3., (a.1 + b.1) / 3.)
+            })
+            .collect();
+
+        let points: Vec<Vector> = match self.vertex(cell) {
+            None => offsets
+                .iter()
+                .map(|&offset| {
+                    let (face, w) = self.locate(cell.face as usize, add(cell.coords(), offset), z);
+                    self.lattice_to_sphere(face, w, z)
+                })
+                .collect(),
+            Some(vertex) => {
+                let centre = self.vertices[vertex];
+                let mut points: Vec<Vector> = Vec::new();
+
+                for (face, f) in self.faces.iter().enumerate() {
+                    let Some(corner) = f.vertices.iter().position(|&v| v == vertex) else {
+                        continue;
+                    };
+
+                    for &offset in &offsets {
+                        let w = add(mul(CORNERS[corner], z), offset);
+
+                        if Self::barycentric(w, z)
+                            .iter()
+                            .all(|&l| l >= -1e-9 * norm(z))
+                        {
+                            let p = self.lattice_to_sphere(face, w, z);
+
+                            if points.iter().all(|&q| dot(p, q) < 1. - 1e-15) {
+                                points.push(p);
+                            }
+                        }
+                    }
+                }
+
+                let ey = normalize(cross(centre, (0.3, 0.5, 0.7)));
+                let ex = cross(ey, centre);
+                points.sort_by(|&a, &b| {
+                    let angle = |p: Vector| dot(p, ey).atan2(dot(p, ex));
+                    angle(a).total_cmp(&angle(b))
+                });
+
+                points
+            }
+        };
+
+        points
+            .into_iter()
+            .map(|p| self.to_geodetic(p, deg))
+            .collect()
+    }
+
+    pub fn neighbours(&self, cell: &HexCell) -> Option<Vec<HexCell>> {
+        if !self.valid(cell) {
+            return None;
+        }
+
+        let z = self.scale(cell.resolution);
+        let mut cells = Vec::new();
+
+        let mut push = |c: HexCell| {
+            if !cells.contains(&c) {
+                cells.push(c);
+            }
+        };
+
+        match self.vertex(cell) {
+            None => {
+                for unit in UNITS {
+                    push(self.canonical(
+                        cell.face as usize,
+                        add(cell.coords(), unit),
+                        cell.resolution,
+                    ));
+                }
+            }
+            Some(vertex) => {
+                for (face, f) in self.faces.iter().enumerate() {
+                    let Some(corner) = f.vertices.iter().position(|&v| v == vertex) else {
+                        continue;
+                    };
+
+                    for unit in UNITS {
+                        let w = add(mul(CORNERS[corner], z), unit);
+
+                        if Self::barycentric(w, z).iter().all(|&l| l >= 0.) {
+                            push(self.canonical(face, w, cell.resolution));
+                        }
+                    }
+                }
+            }
+        }
+
+        Some(cells)
+    }
+
+    pub fn k_ring(&self, cell: &HexCell, k: u32) -> Option<Vec<HexCell>> {
+        if !self.valid(cell) {
+            return None;
+        }
+
+        let mut seen = BTreeSet::from([*cell]);
+        let mut frontier = vec![*cell];
+
+        for _ in 0..k {
+            let mut next = Vec::new();
+
+            for c in &frontier {
+                for neighbour in self.neighbours(c)? {
+                    if seen.insert(neighbour) {
+                        next.push(neighbour);
+                    }
+                }
+            }
+
+            if seen.len() > MAX_CELLS {
+                return None;
+            }
+
+            if next.is_empty() {
+                break;
+            }
+
+            frontier = next;
+        }
+
+        Some(seen.into_iter().collect())
+    }
+
+    pub fn parent(&self, cell: &HexCell) -> Option<HexCell> {
+        if cell.resolution == 0 || !self.valid(cell) {
+            return None;
+        }
+
+        let g = self.generator();
+        let w = mul(cell.coords(), conj(g));
+        let w = round((w.0 / norm(g), w.1 / norm(g)));
+
+        Some(self.canonical(cell.face as usize, w, cell.resolution - 1))
+    }
+
+    pub fn children(&self, cell: &HexCell) -> Option<Vec<HexCell>> {
+        if cell.resolution >= self.max_resolution() || !self.valid(cell) {
+            return None;
+        }
+
+        let centre = self.canonical(
+            cell.face as usize,
+            mul(cell.coords(), self.generator()),
+            cell.resolution + 1,
+        );
+
+        let mut children = vec![centre];
+
+        for neighbour in self.neighbours(&centre)? {
+            if self.parent(&neighbour) == Some(*cell) {
+                children.push(neighbour);
+            }
+        }
+
+        children.sort();
+
+        Some(children)
+    }
+}
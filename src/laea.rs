@@ -0,0 +1,116 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::rcurve::parallel_radius;
+use crate::rsphere::authalic;
+use crate::tmerc::wrap_lon;
+use crate::{authalic2geodetic, geodetic2authalic, Ellipsoid};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LambertAzimuthalEqualArea {
+    pub lat0: f64,
+    pub lon0: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+    pub ellipsoid: Ellipsoid,
+    radius: f64,
+    beta0: f64,
+    d: f64,
+}
+
+impl LambertAzimuthalEqualArea {
+    pub fn new(
+        lat0: f64,
+        lon0: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let lat0 = if deg { lat0.to_radians() } else { lat0 };
+        let lon0 = if deg { lon0.to_radians() } else { lon0 };
+
+        if lat0.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        let radius = authalic(ell);
+        let beta0 = geodetic2authalic(lat0, ell, false)?;
+
+        let d = if FRAC_PI_2 - lat0.abs() < 1e-12 {
+            1.
+        } else {
+            ell.semimajor_axis * parallel_radius(lat0, ell) / (radius * beta0.cos())
+        };
+
+        Some(LambertAzimuthalEqualArea {
+            lat0,
+            lon0,
+            false_easting,
+            false_northing,
+            ellipsoid: ell.clone(),
+            radius,
+            beta0,
+            d,
+        })
+    }
+
+    pub fn etrs89() -> Self {
+        Self::new(52., 10., 4321000., 3210000., &Ellipsoid::grs80(), true).unwrap()
+    }
+
+    pub fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        let beta = geodetic2authalic(lat, &self.ellipsoid, false)?;
+        let dlon = wrap_lon(lon - self.lon0);
+
+        let denom = 1. + self.beta0.sin() * beta.sin() + self.beta0.cos() * beta.cos() * dlon.cos();
+
+        if denom < 1e-12 {
+            return None;
+        }
+
+        let b = self.radius * (2. / denom).sqrt();
+
+        let x = b * self.d * beta.cos() * dlon.sin();
+        let y = b / self.d
+            * (self.beta0.cos() * beta.sin() - self.beta0.sin() * beta.cos() * dlon.cos());
+
+        Some((self.false_easting + x, self.false_northing + y))
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)> {
+        let dx = x - self.false_easting;
+        let dy = y - self.false_northing;
+
+        let rho = (dx / self.d).hypot(self.d * dy);
+
+        if rho > 2. * self.radius * (1. + 1e-12) {
+            return None;
+        }
+
+        let c = 2. * (rho / (2. * self.radius)).min(1.).asin();
+
+        let (beta, dlon) = if rho == 0. {
+            (self.beta0, 0.)
+        } else {
+            let sin_beta =
+                c.cos() * self.beta0.sin() + self.d * dy * c.sin() * self.beta0.cos() / rho;
+            let dlon = (dx * c.sin()).atan2(
+                self.d * rho * self.beta0.cos() * c.cos()
+                    - self.d.powi(2) * dy * self.beta0.sin() * c.sin(),
+            );
+
+            (sin_beta.clamp(-1., 1.).asin(), dlon)
+        };
+
+        let lat = authalic2geodetic(beta, &self.ellipsoid, false)?;
+        let lon = wrap_lon(self.lon0 + dlon);
+
+        let lat = if deg { lat.to_degrees() } else { lat };
+        let lon = if deg { lon.to_degrees() } else { lon };
+
+        Some((lat, lon))
+    }
+}
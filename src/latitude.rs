@@ -128,7 +128,7 @@ pub fn isometric2geodetic(isometric_lat: f64, ell: &Ellipsoid, deg: bool) -> Opt
         isometric_lat
     };
 
-    if isometric_lat.abs() > FRAC_PI_2 {
+    if isometric_lat.is_nan() {
         return None;
     }
 
@@ -158,16 +158,22 @@ pub fn conformal2geodetic(conformal_lat: f64, ell: &Ellipsoid, deg: bool) -> Opt
     let e = ell.eccentricity;
 
     let f1 = e.powi(2) / 2. + 5. * e.powi(4) / 24. + e.powi(6) / 12. + 13. * e.powi(8) / 360.;
-    let f2 = 7. * e.powi(4) / 48. + 2.9 * e.powi(6) / 240. + 811. * e.powi(8) / 11520.;
+    let f2 = 7. * e.powi(4) / 48. + 29. * e.powi(6) / 240. + 811. * e.powi(8) / 11520.;
     let f3 = 7. * e.powi(6) / 120. + 81. * e.powi(8) / 1120.;
     let f4 = 4279. * e.powi(8) / 161280.;
 
-    let geodetic_lat = conformal_lat
+    let mut geodetic_lat = conformal_lat
         + f1 * (2. * conformal_lat).sin()
         + f2 * (4. * conformal_lat).sin()
         + f3 * (6. * conformal_lat).sin()
         + f4 * (8. * conformal_lat).sin();
 
+    if conformal_lat.cos() > 1e-9 {
+        let residual = geodetic2conformal(geodetic_lat, ell, false)? - conformal_lat;
+        geodetic_lat -= residual * (1. - (e * geodetic_lat.sin()).powi(2)) * geodetic_lat.cos()
+            / ((1. - e.powi(2)) * conformal_lat.cos());
+    }
+
     let geodetic_lat = if deg {
         geodetic_lat.to_degrees()
     } else {
@@ -0,0 +1,192 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::rcurve::parallel_radius;
+use crate::tmerc::wrap_lon;
+use crate::{geodetic2isometric, isometric2geodetic, Ellipsoid};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LambertConformalConic {
+    pub lat0: f64,
+    pub lon0: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+    pub ellipsoid: Ellipsoid,
+    n: f64,
+    f: f64,
+    rho0: f64,
+}
+
+impl LambertConformalConic {
+    fn build(
+        lat0: f64,
+        lon0: f64,
+        n: f64,
+        f: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+    ) -> Option<Self> {
+        let mut lcc = LambertConformalConic {
+            lat0,
+            lon0,
+            false_easting,
+            false_northing,
+            ellipsoid: ell.clone(),
+            n,
+            f,
+            rho0: 0.,
+        };
+
+        lcc.rho0 = lcc.rho(lat0)?;
+
+        if !lcc.rho0.is_finite() {
+            return None;
+        }
+
+        Some(lcc)
+    }
+
+    pub fn new_1sp(
+        lat0: f64,
+        lon0: f64,
+        k0: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let lat0 = if deg { lat0.to_radians() } else { lat0 };
+        let lon0 = if deg { lon0.to_radians() } else { lon0 };
+
+        if lat0 == 0. || lat0.abs() >= FRAC_PI_2 || k0 <= 0. {
+            return None;
+        }
+
+        let n = lat0.sin();
+        let psi0 = geodetic2isometric(lat0, ell, false)?;
+        let f = ell.semimajor_axis * k0 * parallel_radius(lat0, ell) / n * (n * psi0).exp();
+
+        Self::build(lat0, lon0, n, f, false_easting, false_northing, ell)
+    }
+
+    pub fn new_2sp(
+        lat1: f64,
+        lat2: f64,
+        lat0: f64,
+        lon0: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let lat1 = if deg { lat1.to_radians() } else { lat1 };
+        let lat2 = if deg { lat2.to_radians() } else { lat2 };
+        let lat0 = if deg { lat0.to_radians() } else { lat0 };
+        let lon0 = if deg { lon0.to_radians() } else { lon0 };
+
+        if lat1.abs() >= FRAC_PI_2 || lat2.abs() >= FRAC_PI_2 || lat0.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        let m1 = parallel_radius(lat1, ell);
+        let m2 = parallel_radius(lat2, ell);
+        let psi1 = geodetic2isometric(lat1, ell, false)?;
+        let psi2 = geodetic2isometric(lat2, ell, false)?;
+
+        let n = if (lat1 - lat2).abs() < 1e-12 {
+            lat1.sin()
+        } else {
+            (m1.ln() - m2.ln()) / (psi2 - psi1)
+        };
+
+        if n == 0. || !n.is_finite() {
+            return None;
+        }
+
+        let f = ell.semimajor_axis * m1 / n * (n * psi1).exp();
+
+        Self::build(lat0, lon0, n, f, false_easting, false_northing, ell)
+    }
+
+    pub fn with_origin(mut self, lat0: f64, deg: bool) -> Option<Self> {
+        let lat0 = if deg { lat0.to_radians() } else { lat0 };
+
+        if lat0.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        self.lat0 = lat0;
+        self.rho0 = self.rho(lat0)?;
+
+        if !self.rho0.is_finite() {
+            return None;
+        }
+
+        Some(self)
+    }
+
+    fn rho(&self, lat: f64) -> Option<f64> {
+        let psi = geodetic2isometric(lat, &self.ellipsoid, false)?;
+
+        Some(self.f * (-self.n * psi).exp())
+    }
+
+    pub fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        let rho = self.rho(lat)?;
+
+        if !rho.is_finite() {
+            return None;
+        }
+
+        let theta = self.n * wrap_lon(lon - self.lon0);
+
+        Some((
+            self.false_easting + rho * theta.sin(),
+            self.false_northing + self.rho0 - rho * theta.cos(),
+        ))
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)> {
+        let dx = (x - self.false_easting) * self.n.signum();
+        let dy = (self.rho0 - (y - self.false_northing)) * self.n.signum();
+
+        let rho = dx.hypot(dy) * self.n.signum();
+        let psi = -(rho / self.f).ln() / self.n;
+
+        let lat = isometric2geodetic(psi, &self.ellipsoid, false)?;
+        let lon = wrap_lon(self.lon0 + dx.atan2(dy) / self.n);
+
+        let lat = if deg { lat.to_degrees() } else { lat };
+        let lon = if deg { lon.to_degrees() } else { lon };
+
+        Some((lat, lon))
+    }
+
+    pub fn convergence(&self, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        if lat.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        let gamma = self.n * wrap_lon(lon - self.lon0);
+
+        Some(if deg { gamma.to_degrees() } else { gamma })
+    }
+
+    pub fn scale(&self, lat: f64, _lon: f64, deg: bool) -> Option<f64> {
+        let lat = if deg { lat.to_radians() } else { lat };
+
+        if lat.abs() >= FRAC_PI_2 {
+            return None;
+        }
+
+        let rho = self.rho(lat)?;
+
+        Some(self.n * rho / (self.ellipsoid.semimajor_axis * parallel_radius(lat, &self.ellipsoid)))
+    }
+}
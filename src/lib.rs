@@ -1,42 +1,140 @@
+pub mod aeqd;
 pub mod aer;
+pub mod albers;
+pub mod cassini;
+pub mod deflection;
 pub mod ecef;
+pub mod egm;
 pub mod eci;
 pub mod ellipsoid;
 pub mod enu;
+pub mod equal_earth;
+pub mod equirectangular;
+pub mod gars;
+pub mod geohash;
+pub mod geoid;
+pub mod gnomonic;
+pub mod gravity;
 pub mod haversine;
+pub mod height;
+pub mod helmert;
+pub mod hexgrid;
+pub mod laea;
 pub mod latitude;
+pub mod lcc;
 pub mod los;
+pub mod maidenhead;
+pub mod mercator;
+pub mod mgrs;
 pub mod ned;
+pub mod olc;
+pub mod omerc;
+pub mod osgb;
+pub mod plate;
+pub mod polar;
+pub mod projection;
 pub mod rcurve;
+pub mod robinson;
+pub mod s2;
 pub mod sidereal;
+pub mod sinusoidal;
 pub mod spherical;
+pub mod tile;
+pub mod tissot;
+pub mod tmerc;
+pub mod ups;
 pub mod utils;
+pub mod utm;
 pub mod vallado;
 pub mod vincenty;
 pub mod lox;
 pub mod rsphere;
 
+pub use aeqd::AzimuthalEquidistant;
 pub use aer::{aer2ecef, aer2eci, aer2geodetic, ecef2aer, eci2aer, geodetic2aer};
+pub use albers::AlbersEqualArea;
+pub use cassini::CassiniSoldner;
+pub use deflection::{
+    aer2geodetic_astronomic, astronomic2geodetic, astronomic2geodetic_azel, deflection,
+    ecef2enu_astronomic, ecef2ned_astronomic, enu2ecef_astronomic, geodetic2aer_astronomic,
+    geodetic2astronomic, geodetic2astronomic_azel, laplace_azimuth, ned2ecef_astronomic,
+};
 pub use ecef::{
     ecef2enu, ecef2enuv, ecef2geodetic, eci2geodetic, enu2ecef, enu2uvw, geodetic2ecef,
     geodetic2eci, uvw2enu,
 };
 pub use eci::{ecef2eci, eci2ecef};
-pub use ellipsoid::Ellipsoid;
+pub use egm::GravityModel;
+pub use ellipsoid::{Ellipsoid, PhysicalParameters};
 pub use enu::{aer2enu, enu2aer, enu2geodetic, geodetic2enu};
+pub use equal_earth::EqualEarth;
+pub use equirectangular::Equirectangular;
+pub use gars::{gars2geodetic, gars_bbox, gars_cells, gars_polygon, geodetic2gars};
+pub use geohash::{
+    geodetic2geohash, geohash2geodetic, geohash_bbox, geohash_circle, geohash_neighbours,
+    geohash_polygon,
+};
+pub use geoid::{
+    ecef2geodetic_orthometric, ellipsoidal2orthometric, geodetic2ecef_orthometric,
+    geodetic2enu_orthometric, orthometric2ellipsoidal, Geoid, GeoidGrid, Interpolation,
+};
+pub use gnomonic::{geodesic_nearest, Gnomonic};
+pub use gravity::{
+    equatorial_gravity, gravity_ecef, gravity_enu, gravity_ned, normal_gravity, normal_potential,
+    polar_gravity, somigliana, zonal_harmonic,
+};
 pub use haversine::{anglesep, haversine};
+pub use height::{
+    ecef2geodetic_height, geodetic2ecef_height, geodetic2enu_height, geopotential2normal,
+    geopotential2orthometric, normal2geopotential, orthometric2geopotential, Height, HeightKind,
+    VerticalDatum,
+};
+pub use helmert::Helmert;
+pub use hexgrid::{HexCell, HexGrid};
+pub use laea::LambertAzimuthalEqualArea;
 pub use latitude::{
     authalic2geodetic, conformal2geodetic, geoc2geod, geocentric2geodetic, geod2geoc,
     geodetic2authalic, geodetic2conformal, geodetic2geocentric, geodetic2isometric,
     geodetic2parametric, geodetic2rectifying, isometric2geodetic, parametric2geodetic,
     rectifying2geodetic,
 };
+pub use lcc::LambertConformalConic;
 pub use los::look_at_spheroid;
+pub use maidenhead::{
+    geodetic2maidenhead, maidenhead2geodetic, maidenhead_bbox, maidenhead_distance,
+};
+pub use mercator::Mercator;
+pub use mgrs::{geodetic2mgrs, mgrs2geodetic, mgrs2grid, Mgrs};
 pub use ned::{aer2ned, ecef2ned, ecef2nedv, geodetic2ned, ned2aer, ned2ecef, ned2geodetic};
+pub use olc::{
+    geodetic2olc, olc2geodetic, olc_bbox, olc_is_full, olc_is_short, olc_is_valid, olc_recover,
+    olc_shorten,
+};
+pub use omerc::HotineObliqueMercator;
+pub use osgb::{geodetic2gridref, gridref2geodetic, GridRef, NationalGrid, Ostn15};
+pub use plate::{
+    plate_propagate, plate_propagate_geodetic, plate_velocity, plate_velocity_enu, EulerPole,
+    PlateModel,
+};
+pub use polar::PolarStereographic;
+pub use projection::{Crs, Pipeline, Projection};
 pub use rcurve::{geocentric_radius, meridian, parallel, transverse};
+pub use robinson::Robinson;
+pub use s2::{geodetic2s2, s2_circle, s2_polygon, S2CellId, S2_MAX_LEVEL};
 pub use sidereal::{datetime2sidereal, greenwichsrt, juliandate};
+pub use sinusoidal::Sinusoidal;
 pub use spherical::{geodetic2spherical, spherical2geodetic};
+pub use tile::{
+    geodetic2pixel, geodetic2tile, pixel2geodetic, quadkey2tile, tile2geodetic, tile2quadkey,
+    tile_bbox,
+};
+pub use tissot::{tissot, Tissot};
+pub use tmerc::TransverseMercator;
+pub use ups::{geodetic2ups, geodetic2ups_hemisphere, ups2geodetic, ups_projection, Ups};
 pub use utils::{cart2pol, cart2sph, is_close, pol2cart, sanitize, sph2cart};
+pub use utm::{
+    geodetic2utm, geodetic2utm_zone, utm2geodetic, utm_band, utm_projection, utm_zone, Utm,
+};
 pub use vallado::{azel2radec, radec2azel};
 pub use vincenty::{track2, vdist, vreckon};
 pub use rsphere::{eqavol, authalic, rectifying, euler, curve, triaxial, Method};
\ No newline at end of file
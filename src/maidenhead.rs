@@ -0,0 +1,119 @@
+use crate::{vdist, Ellipsoid};
+
+const DIVISIONS: [u64; 5] = [18, 10, 24, 10, 24];
+
+fn pairs(length: usize) -> Option<usize> {
+    if !(2..=10).contains(&length) || !length.is_multiple_of(2) {
+        None
+    } else {
+        Some(length / 2)
+    }
+}
+
+fn total(pairs: usize) -> u64 {
+    DIVISIONS[..pairs].iter().product()
+}
+
+fn symbol(pair: usize, value: u64) -> char {
+    match pair {
+        0 => (b'A' + value as u8) as char,
+        1 | 3 => (b'0' + value as u8) as char,
+        _ => (b'a' + value as u8) as char,
+    }
+}
+
+fn value(pair: usize, c: char) -> Option<u64> {
+    if !c.is_ascii() {
+        return None;
+    }
+
+    let c = c.to_ascii_uppercase() as u8;
+
+    let v = match pair {
+        1 | 3 => c.checked_sub(b'0')?,
+        _ => c.checked_sub(b'A')?,
+    };
+
+    if (v as u64) < DIVISIONS[pair] {
+        Some(v as u64)
+    } else {
+        None
+    }
+}
+
+pub fn geodetic2maidenhead(lat: f64, lon: f64, length: usize, deg: bool) -> Option<String> {
+    let lat = if deg { lat } else { lat.to_degrees() };
+    let lon = if deg { lon } else { lon.to_degrees() };
+
+    if lat.abs() > 90. {
+        return None;
+    }
+
+    let pairs = pairs(length)?;
+    let n = total(pairs);
+
+    let x = (((lon + 180.).rem_euclid(360.) / 360. * n as f64) as u64).min(n - 1);
+    let y = (((lat + 90.) / 180. * n as f64) as u64).min(n - 1);
+
+    let mut locator = String::with_capacity(length);
+    let mut size = n;
+
+    for (pair, &divisions) in DIVISIONS[..pairs].iter().enumerate() {
+        size /= divisions;
+
+        locator.push(symbol(pair, x / size % divisions));
+        locator.push(symbol(pair, y / size % divisions));
+    }
+
+    Some(locator)
+}
+
+pub fn maidenhead_bbox(locator: &str, deg: bool) -> Option<(f64, f64, f64, f64)> {
+    let chars: Vec<char> = locator.trim().chars().collect();
+    let pairs = pairs(chars.len())?;
+
+    let (mut x, mut y) = (0, 0);
+
+    for (pair, &divisions) in DIVISIONS[..pairs].iter().enumerate() {
+        x = x * divisions + value(pair, chars[2 * pair])?;
+        y = y * divisions + value(pair, chars[2 * pair + 1])?;
+    }
+
+    let dlon = 360. / total(pairs) as f64;
+    let dlat = 180. / total(pairs) as f64;
+
+    let south = -90. + y as f64 * dlat;
+    let west = -180. + x as f64 * dlon;
+    let (north, east) = (south + dlat, west + dlon);
+
+    if deg {
+        Some((south, west, north, east))
+    } else {
+        Some((
+            south.to_radians(),
+            west.to_radians(),
+            north.to_radians(),
+            east.to_radians(),
+        ))
+    }
+}
+
+pub fn maidenhead2geodetic(locator: &str, deg: bool) -> Option<(f64, f64)> {
+    let (south, west, north, east) = maidenhead_bbox(locator, deg)?;
+
+    Some(((south + north) / 2., (west + east) / 2.))
+}
+
+pub fn maidenhead_distance(
+    locator1: &str,
+    locator2: &str,
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64)> {
+    let (lat1, lon1) = maidenhead2geodetic(locator1, false)?;
+    let (lat2, lon2) = maidenhead2geodetic(locator2, false)?;
+
+    let (distance, azimuth) = vdist(lat1, lon1, lat2, lon2, ell, false)?;
+
+    Some((distance, if deg { azimuth.to_degrees() } else { azimuth }))
+}
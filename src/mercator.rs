@@ -0,0 +1,115 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::rcurve::parallel_radius;
+use crate::tmerc::wrap_lon;
+use crate::{geodetic2isometric, isometric2geodetic, Ellipsoid};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mercator {
+    pub lon0: f64,
+    pub k0: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+    pub ellipsoid: Ellipsoid,
+}
+
+impl Mercator {
+    pub fn new(
+        lon0: f64,
+        k0: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let lon0 = if deg { lon0.to_radians() } else { lon0 };
+
+        if k0 <= 0. {
+            return None;
+        }
+
+        Some(Mercator {
+            lon0,
+            k0,
+            false_easting,
+            false_northing,
+            ellipsoid: ell.clone(),
+        })
+    }
+
+    pub fn new_2sp(
+        lat1: f64,
+        lon0: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let lat1 = if deg { lat1.to_radians() } else { lat1 };
+
+        if lat1.abs() >= FRAC_PI_2 {
+            return None;
+        }
+
+        let k0 = parallel_radius(lat1, ell);
+
+        Self::new(lon0, k0, false_easting, false_northing, ell, deg)
+    }
+
+    pub fn web() -> Self {
+        let a = Ellipsoid::wgs84().semimajor_axis;
+        let sphere = Ellipsoid::new(a, a, "Web Mercator sphere", "webmercator");
+
+        Self::new(0., 1., 0., 0., &sphere, false).unwrap()
+    }
+
+    pub fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        if lat.abs() >= FRAC_PI_2 {
+            return None;
+        }
+
+        let psi = geodetic2isometric(lat, &self.ellipsoid, false)?;
+        let r = self.ellipsoid.semimajor_axis * self.k0;
+
+        Some((
+            self.false_easting + r * wrap_lon(lon - self.lon0),
+            self.false_northing + r * psi,
+        ))
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)> {
+        let r = self.ellipsoid.semimajor_axis * self.k0;
+
+        let psi = (y - self.false_northing) / r;
+        let lat = isometric2geodetic(psi, &self.ellipsoid, false)?;
+        let lon = wrap_lon(self.lon0 + (x - self.false_easting) / r);
+
+        let lat = if deg { lat.to_degrees() } else { lat };
+        let lon = if deg { lon.to_degrees() } else { lon };
+
+        Some((lat, lon))
+    }
+
+    pub fn convergence(&self, lat: f64, _lon: f64, deg: bool) -> Option<f64> {
+        let lat = if deg { lat.to_radians() } else { lat };
+
+        if lat.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        Some(0.)
+    }
+
+    pub fn scale(&self, lat: f64, _lon: f64, deg: bool) -> Option<f64> {
+        let lat = if deg { lat.to_radians() } else { lat };
+
+        if lat.abs() >= FRAC_PI_2 {
+            return None;
+        }
+
+        Some(self.k0 / parallel_radius(lat, &self.ellipsoid))
+    }
+}
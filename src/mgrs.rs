@@ -0,0 +1,364 @@
+use std::fmt;
+
+use crate::{
+    geodetic2ups_hemisphere, geodetic2utm_zone, ups2geodetic, utm2geodetic, utm_projection,
+    utm_zone, Ellipsoid,
+};
+
+const COLUMNS: [&[u8]; 3] = [b"ABCDEFGH", b"JKLMNPQR", b"STUVWXYZ"];
+const ROWS: &[u8] = b"ABCDEFGHJKLMNPQRSTUV";
+const BANDS: &[u8] = b"CDEFGHJKLMNPQRSTUVWX";
+
+const SQUARE: f64 = 100_000.;
+const CYCLE: f64 = 2_000_000.;
+
+// letter, lowest column, highest column, highest row, false easting, false northing
+const UPS_TABLE: [(u8, u8, u8, u8, f64, f64); 4] = [
+    (b'A', b'J', b'Z', b'Z', 800_000., 800_000.),
+    (b'B', b'A', b'R', b'Z', 2_000_000., 800_000.),
+    (b'Y', b'J', b'Z', b'P', 800_000., 1_300_000.),
+    (b'Z', b'A', b'J', b'P', 2_000_000., 1_300_000.),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mgrs {
+    pub zone: u8,
+    pub band: char,
+    pub column: char,
+    pub row: char,
+    pub easting: u32,
+    pub northing: u32,
+    pub precision: u8,
+}
+
+fn old_lettering(ell: &Ellipsoid) -> bool {
+    matches!(ell.model.as_str(), "bessel" | "clarke1866" | "clarke1860")
+}
+
+fn row_offset(zone: u8, ell: &Ellipsoid) -> usize {
+    let offset = if zone.is_multiple_of(2) { 5 } else { 0 };
+
+    if old_lettering(ell) {
+        offset + 10
+    } else {
+        offset
+    }
+}
+
+fn ups_table(band: char) -> Option<(u8, u8, u8, u8, f64, f64)> {
+    UPS_TABLE.iter().find(|t| t.0 as char == band).copied()
+}
+
+fn letter_index(letter: u8) -> u8 {
+    letter - b'A'
+}
+
+fn is_letter(c: char) -> bool {
+    c.is_ascii_uppercase() && c != 'I' && c != 'O'
+}
+
+impl Mgrs {
+    pub fn parse(s: &str) -> Option<Mgrs> {
+        let s: Vec<char> = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+
+        let digits = s.iter().take_while(|c| c.is_ascii_digit()).count();
+
+        if digits > 2 || s.len() < digits + 3 {
+            return None;
+        }
+
+        let zone = if digits == 0 {
+            0
+        } else {
+            s[..digits].iter().collect::<String>().parse::<u8>().ok()?
+        };
+
+        let band = s[digits];
+        let column = s[digits + 1];
+        let row = s[digits + 2];
+
+        if !is_letter(band) || !is_letter(column) || !is_letter(row) {
+            return None;
+        }
+
+        if zone == 0 {
+            if digits != 0 || !"ABYZ".contains(band) {
+                return None;
+            }
+        } else if zone > 60 || !BANDS.contains(&(band as u8)) {
+            return None;
+        }
+
+        let rest = &s[digits + 3..];
+
+        if !rest.len().is_multiple_of(2)
+            || rest.len() > 10
+            || !rest.iter().all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let precision = (rest.len() / 2) as u8;
+        let scale = 10u32.pow(5 - precision as u32);
+
+        let parse = |d: &[char]| -> Option<u32> {
+            if d.is_empty() {
+                Some(0)
+            } else {
+                d.iter().collect::<String>().parse::<u32>().ok()
+            }
+        };
+
+        let easting = parse(&rest[..precision as usize])? * scale;
+        let northing = parse(&rest[precision as usize..])? * scale;
+
+        Some(Mgrs {
+            zone,
+            band,
+            column,
+            row,
+            easting,
+            northing,
+            precision,
+        })
+    }
+
+    fn digits(&self) -> (String, String) {
+        let p = self.precision as usize;
+
+        if p == 0 {
+            return (String::new(), String::new());
+        }
+
+        let scale = 10u32.pow(5 - self.precision as u32);
+
+        (
+            format!("{:0p$}", self.easting / scale, p = p),
+            format!("{:0p$}", self.northing / scale, p = p),
+        )
+    }
+
+    fn prefix(&self) -> String {
+        if self.zone == 0 {
+            format!("{}", self.band)
+        } else {
+            format!("{}{}", self.zone, self.band)
+        }
+    }
+
+    pub fn usng(&self) -> String {
+        let (e, n) = self.digits();
+        let mut usng = format!("{} {}{}", self.prefix(), self.column, self.row);
+
+        if self.precision > 0 {
+            usng = format!("{} {} {}", usng, e, n);
+        }
+
+        usng
+    }
+
+    pub fn cell_size(&self) -> f64 {
+        10f64.powi(5 - self.precision as i32)
+    }
+}
+
+impl fmt::Display for Mgrs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (e, n) = self.digits();
+
+        write!(f, "{}{}{}{}{}", self.prefix(), self.column, self.row, e, n)
+    }
+}
+
+fn truncate(value: f64, precision: u8) -> u32 {
+    let scale = 10u32.pow(5 - precision as u32);
+    let value = value.floor().rem_euclid(SQUARE) as u32;
+
+    value / scale * scale
+}
+
+pub fn geodetic2mgrs(
+    lat: f64,
+    lon: f64,
+    precision: u8,
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<Mgrs> {
+    if precision > 5 {
+        return None;
+    }
+
+    let lat_deg = if deg { lat } else { lat.to_degrees() };
+
+    if lat_deg.abs() > 90. {
+        return None;
+    }
+
+    if (-80. ..=84.).contains(&lat_deg) {
+        let (zone, band) = utm_zone(lat, lon, deg)?;
+        let utm = geodetic2utm_zone(lat, lon, zone, ell, deg)?;
+
+        let easting = utm.easting.floor();
+        let northing = utm.northing.floor();
+
+        let column =
+            *COLUMNS[(zone as usize - 1) % 3].get(((easting / SQUARE) as usize).checked_sub(1)?)?;
+        let row_index = (northing.rem_euclid(CYCLE) / SQUARE) as usize + row_offset(zone, ell);
+
+        return Some(Mgrs {
+            zone,
+            band,
+            column: column as char,
+            row: ROWS[row_index % 20] as char,
+            easting: truncate(easting, precision),
+            northing: truncate(northing, precision),
+            precision,
+        });
+    }
+
+    let ups = geodetic2ups_hemisphere(lat, lon, lat_deg > 0., ell, deg)?;
+    let (_, low, _, _, false_easting, false_northing) = ups_table(ups.band)?;
+
+    let easting = ups.easting.floor();
+    let northing = ups.northing.floor();
+
+    let mut row = ((northing - false_northing) / SQUARE) as u8;
+
+    if row > letter_index(b'H') {
+        row += 1;
+    }
+
+    if row > letter_index(b'N') {
+        row += 1;
+    }
+
+    let mut column = letter_index(low) + ((easting - false_easting) / SQUARE) as u8;
+
+    if easting < 2_000_000. {
+        if column > letter_index(b'L') {
+            column += 3;
+        }
+
+        if column > letter_index(b'U') {
+            column += 2;
+        }
+    } else {
+        if column > letter_index(b'C') {
+            column += 2;
+        }
+
+        if column > letter_index(b'H') {
+            column += 1;
+        }
+
+        if column > letter_index(b'L') {
+            column += 3;
+        }
+    }
+
+    Some(Mgrs {
+        zone: 0,
+        band: ups.band,
+        column: (b'A' + column) as char,
+        row: (b'A' + row) as char,
+        easting: truncate(easting, precision),
+        northing: truncate(northing, precision),
+        precision,
+    })
+}
+
+pub fn mgrs2grid(mgrs: &Mgrs, ell: &Ellipsoid) -> Option<(u8, bool, f64, f64)> {
+    let column = mgrs.column as u8;
+    let row = mgrs.row as u8;
+
+    if mgrs.zone == 0 {
+        let (_, low, high, row_high, false_easting, false_northing) = ups_table(mgrs.band)?;
+
+        if column < low || column > high || b"DEMNVW".contains(&column) || row > row_high {
+            return None;
+        }
+
+        let mut northing = letter_index(row) as f64 * SQUARE + false_northing;
+
+        if row > b'I' {
+            northing -= SQUARE;
+        }
+
+        if row > b'O' {
+            northing -= SQUARE;
+        }
+
+        let mut easting = (column - low) as f64 * SQUARE + false_easting;
+
+        if low != b'A' {
+            if column > b'L' {
+                easting -= 300_000.;
+            }
+
+            if column > b'U' {
+                easting -= 200_000.;
+            }
+        } else {
+            if column > b'C' {
+                easting -= 200_000.;
+            }
+
+            if column > b'I' {
+                easting -= SQUARE;
+            }
+
+            if column > b'L' {
+                easting -= 300_000.;
+            }
+        }
+
+        return Some((
+            0,
+            mgrs.band >= 'Y',
+            easting + mgrs.easting as f64,
+            northing + mgrs.northing as f64,
+        ));
+    }
+
+    if !(1..=60).contains(&mgrs.zone) {
+        return None;
+    }
+
+    let column = COLUMNS[(mgrs.zone as usize - 1) % 3]
+        .iter()
+        .position(|&c| c == column)?;
+    let row = ROWS.iter().position(|&c| c == row)?;
+    let band = BANDS.iter().position(|&c| c == mgrs.band as u8)?;
+
+    let easting = (column + 1) as f64 * SQUARE + mgrs.easting as f64;
+    let northing =
+        ((row + 20 - row_offset(mgrs.zone, ell) % 20) % 20) as f64 * SQUARE + mgrs.northing as f64;
+
+    let northern = mgrs.band >= 'N';
+    let lat = if band == BANDS.len() - 1 {
+        78.
+    } else {
+        -76. + 8. * band as f64
+    };
+
+    let tmerc = utm_projection(mgrs.zone, northern, ell)?;
+    let (_, centre) = tmerc.forward(lat, tmerc.lon0.to_degrees(), true)?;
+    let northing = northing + ((centre - northing) / CYCLE).round() * CYCLE;
+
+    Some((mgrs.zone, northern, easting, northing))
+}
+
+pub fn mgrs2geodetic(mgrs: &Mgrs, ell: &Ellipsoid, deg: bool) -> Option<(f64, f64)> {
+    let (zone, northern, easting, northing) = mgrs2grid(mgrs, ell)?;
+    let half = mgrs.cell_size() / 2.;
+
+    if zone == 0 {
+        ups2geodetic(northern, easting + half, northing + half, ell, deg)
+    } else {
+        utm2geodetic(zone, northern, easting + half, northing + half, ell, deg)
+    }
+}
@@ -0,0 +1,296 @@
+const ALPHABET: &[u8] = b"23456789CFGHJMPQRVWX";
+const SEPARATOR: char = '+';
+const SEPARATOR_POSITION: usize = 8;
+const PADDING: char = '0';
+
+const BASE: i64 = 20;
+const PAIR_LENGTH: usize = 10;
+const MAX_LENGTH: usize = 15;
+const GRID_ROWS: i64 = 5;
+const GRID_COLUMNS: i64 = 4;
+
+const PAIR_PRECISION: i64 = 8000;
+const LAT_PRECISION: i64 = PAIR_PRECISION * 3125;
+const LON_PRECISION: i64 = PAIR_PRECISION * 1024;
+
+const PAIR_RESOLUTIONS: [f64; 5] = [20., 1., 0.05, 0.0025, 0.000125];
+const MIN_TRIMMABLE: usize = 6;
+
+fn digit(c: char) -> Option<i64> {
+    ALPHABET
+        .iter()
+        .position(|&b| b as char == c.to_ascii_uppercase())
+        .map(|i| i as i64)
+}
+
+pub fn olc_is_valid(code: &str) -> bool {
+    let separator = match code.find(SEPARATOR) {
+        Some(i) => i,
+        None => return false,
+    };
+
+    if code.matches(SEPARATOR).count() > 1 || separator > SEPARATOR_POSITION || separator % 2 == 1 {
+        return false;
+    }
+
+    if let Some(start) = code.find(PADDING) {
+        if separator < SEPARATOR_POSITION || start == 0 || !code.ends_with(SEPARATOR) {
+            return false;
+        }
+
+        let padding = &code[start..separator];
+
+        if !padding.chars().all(|c| c == PADDING)
+            || padding.len() % 2 == 1
+            || padding.len() > SEPARATOR_POSITION - 2
+        {
+            return false;
+        }
+    }
+
+    if code.len() - separator - 1 == 1 {
+        return false;
+    }
+
+    code.chars()
+        .filter(|&c| c != SEPARATOR && c != PADDING)
+        .all(|c| digit(c).is_some())
+}
+
+pub fn olc_is_short(code: &str) -> bool {
+    olc_is_valid(code) && code.find(SEPARATOR).unwrap() < SEPARATOR_POSITION
+}
+
+pub fn olc_is_full(code: &str) -> bool {
+    if !olc_is_valid(code) || olc_is_short(code) {
+        return false;
+    }
+
+    let mut chars = code.chars();
+
+    match (chars.next().and_then(digit), chars.next().and_then(digit)) {
+        (Some(lat), Some(lon)) => lat * BASE < 180 && lon * BASE < 360,
+        _ => false,
+    }
+}
+
+pub fn geodetic2olc(lat: f64, lon: f64, length: usize, deg: bool) -> Option<String> {
+    let lat = if deg { lat } else { lat.to_degrees() };
+    let lon = if deg { lon } else { lon.to_degrees() };
+
+    if lat.is_nan() || lon.is_nan() || !(2..=MAX_LENGTH).contains(&length) {
+        return None;
+    }
+
+    if length < PAIR_LENGTH && length % 2 == 1 {
+        return None;
+    }
+
+    let lat_range = 180 * LAT_PRECISION;
+    let lon_range = 360 * LON_PRECISION;
+
+    let mut lat_value =
+        ((lat * LAT_PRECISION as f64).floor() as i64 + 90 * LAT_PRECISION).clamp(0, lat_range - 1);
+    let mut lon_value =
+        ((lon * LON_PRECISION as f64).floor() as i64 + 180 * LON_PRECISION).rem_euclid(lon_range);
+
+    let mut code = Vec::with_capacity(MAX_LENGTH + 1);
+
+    if length > PAIR_LENGTH {
+        for _ in 0..MAX_LENGTH - PAIR_LENGTH {
+            let index = (lat_value % GRID_ROWS) * GRID_COLUMNS + lon_value % GRID_COLUMNS;
+            code.push(ALPHABET[index as usize]);
+
+            lat_value /= GRID_ROWS;
+            lon_value /= GRID_COLUMNS;
+        }
+    } else {
+        lat_value /= GRID_ROWS.pow(5);
+        lon_value /= GRID_COLUMNS.pow(5);
+    }
+
+    for _ in 0..PAIR_LENGTH / 2 {
+        code.push(ALPHABET[(lon_value % BASE) as usize]);
+        code.push(ALPHABET[(lat_value % BASE) as usize]);
+
+        lat_value /= BASE;
+        lon_value /= BASE;
+    }
+
+    code.reverse();
+
+    let code: String = code.into_iter().map(|b| b as char).collect();
+    let (head, tail) = code.split_at(SEPARATOR_POSITION);
+
+    if length >= SEPARATOR_POSITION {
+        Some(format!(
+            "{}{}{}",
+            head,
+            SEPARATOR,
+            &tail[..length - SEPARATOR_POSITION]
+        ))
+    } else {
+        Some(format!(
+            "{}{}{}",
+            &head[..length],
+            PADDING.to_string().repeat(SEPARATOR_POSITION - length),
+            SEPARATOR
+        ))
+    }
+}
+
+fn decode(code: &str) -> Option<(f64, f64, f64, f64, usize)> {
+    if !olc_is_full(code) {
+        return None;
+    }
+
+    let digits: Vec<i64> = code
+        .chars()
+        .filter(|&c| c != SEPARATOR && c != PADDING)
+        .take(MAX_LENGTH)
+        .map(digit)
+        .collect::<Option<_>>()?;
+
+    let mut lat = -90 * PAIR_PRECISION;
+    let mut lon = -180 * PAIR_PRECISION;
+    let mut place = BASE.pow(4);
+
+    let pairs = digits.len().min(PAIR_LENGTH);
+
+    for i in (0..pairs).step_by(2) {
+        lat += digits[i] * place;
+        lon += digits[i + 1] * place;
+
+        if i + 2 < pairs {
+            place /= BASE;
+        }
+    }
+
+    let mut lat_size = place as f64 / PAIR_PRECISION as f64;
+    let mut lon_size = place as f64 / PAIR_PRECISION as f64;
+
+    let (mut grid_lat, mut grid_lon) = (0, 0);
+
+    if digits.len() > PAIR_LENGTH {
+        let mut row_place = GRID_ROWS.pow(4);
+        let mut column_place = GRID_COLUMNS.pow(4);
+
+        for (i, &d) in digits.iter().enumerate().skip(PAIR_LENGTH) {
+            grid_lat += d / GRID_COLUMNS * row_place;
+            grid_lon += d % GRID_COLUMNS * column_place;
+
+            if i + 1 < digits.len() {
+                row_place /= GRID_ROWS;
+                column_place /= GRID_COLUMNS;
+            }
+        }
+
+        lat_size = row_place as f64 / LAT_PRECISION as f64;
+        lon_size = column_place as f64 / LON_PRECISION as f64;
+    }
+
+    let south = lat as f64 / PAIR_PRECISION as f64 + grid_lat as f64 / LAT_PRECISION as f64;
+    let west = lon as f64 / PAIR_PRECISION as f64 + grid_lon as f64 / LON_PRECISION as f64;
+
+    Some((south, west, south + lat_size, west + lon_size, digits.len()))
+}
+
+pub fn olc_bbox(code: &str, deg: bool) -> Option<(f64, f64, f64, f64)> {
+    let (south, west, north, east, _) = decode(code)?;
+
+    if deg {
+        Some((south, west, north, east))
+    } else {
+        Some((
+            south.to_radians(),
+            west.to_radians(),
+            north.to_radians(),
+            east.to_radians(),
+        ))
+    }
+}
+
+fn centre(south: f64, west: f64, north: f64, east: f64) -> (f64, f64) {
+    (
+        (south + (north - south) / 2.).min(90.),
+        (west + (east - west) / 2.).min(180.),
+    )
+}
+
+pub fn olc2geodetic(code: &str, deg: bool) -> Option<(f64, f64)> {
+    let (south, west, north, east, _) = decode(code)?;
+    let (lat, lon) = centre(south, west, north, east);
+
+    if deg {
+        Some((lat, lon))
+    } else {
+        Some((lat.to_radians(), lon.to_radians()))
+    }
+}
+
+fn normalize(lat: f64, lon: f64, deg: bool) -> (f64, f64) {
+    let lat = if deg { lat } else { lat.to_degrees() };
+    let lon = if deg { lon } else { lon.to_degrees() };
+
+    (lat.clamp(-90., 90.), (lon + 180.).rem_euclid(360.) - 180.)
+}
+
+pub fn olc_shorten(code: &str, lat: f64, lon: f64, deg: bool) -> Option<String> {
+    if code.contains(PADDING) {
+        return None;
+    }
+
+    let (south, west, north, east, length) = decode(code)?;
+
+    if length < MIN_TRIMMABLE {
+        return None;
+    }
+
+    let (lat, lon) = normalize(lat, lon, deg);
+    let (clat, clon) = centre(south, west, north, east);
+    let range = (clat - lat).abs().max((clon - lon).abs());
+    let code = code.to_ascii_uppercase();
+
+    for i in (1..PAIR_RESOLUTIONS.len() - 1).rev() {
+        if range < PAIR_RESOLUTIONS[i] * 0.3 {
+            return Some(code[(i + 1) * 2..].to_string());
+        }
+    }
+
+    Some(code)
+}
+
+pub fn olc_recover(code: &str, lat: f64, lon: f64, deg: bool) -> Option<String> {
+    if !olc_is_short(code) {
+        return if olc_is_full(code) {
+            Some(code.to_ascii_uppercase())
+        } else {
+            None
+        };
+    }
+
+    let (lat, lon) = normalize(lat, lon, deg);
+    let code = code.to_ascii_uppercase();
+
+    let padding = SEPARATOR_POSITION - code.find(SEPARATOR)?;
+    let resolution = BASE.pow(2) as f64 / BASE.pow(padding as u32 / 2) as f64;
+    let half = resolution / 2.;
+
+    let reference = geodetic2olc(lat, lon, PAIR_LENGTH, true)?;
+    let (south, west, north, east, length) = decode(&format!("{}{}", &reference[..padding], code))?;
+    let (mut clat, mut clon) = centre(south, west, north, east);
+
+    if lat + half < clat && clat - resolution >= -90. {
+        clat -= resolution;
+    } else if lat - half > clat && clat + resolution <= 90. {
+        clat += resolution;
+    }
+
+    if lon + half < clon {
+        clon -= resolution;
+    } else if lon - half > clon {
+        clon += resolution;
+    }
+
+    geodetic2olc(clat, clon, length, true)
+}
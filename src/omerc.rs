@@ -0,0 +1,235 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use crate::tmerc::wrap_lon;
+use crate::{conformal2geodetic, geodetic2isometric, Ellipsoid};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HotineObliqueMercator {
+    pub lat_c: f64,
+    pub lon_c: f64,
+    pub azimuth: f64,
+    pub rectified: f64,
+    pub kc: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+    pub ellipsoid: Ellipsoid,
+    a: f64,
+    b: f64,
+    h: f64,
+    gamma0: f64,
+    lon0: f64,
+    uc: f64,
+}
+
+impl HotineObliqueMercator {
+    fn build(
+        lat_c: f64,
+        lon_c: f64,
+        azimuth: f64,
+        rectified: f64,
+        kc: f64,
+        false_easting: f64,
+        false_northing: f64,
+        centre: bool,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let lat_c = if deg { lat_c.to_radians() } else { lat_c };
+        let lon_c = if deg { lon_c.to_radians() } else { lon_c };
+        let azimuth = if deg { azimuth.to_radians() } else { azimuth };
+        let rectified = if deg {
+            rectified.to_radians()
+        } else {
+            rectified
+        };
+
+        if lat_c.abs() >= FRAC_PI_2 || lat_c == 0. || kc <= 0. {
+            return None;
+        }
+
+        let e2 = ell.eccentricity.powi(2);
+        let sin_c = lat_c.sin();
+        let cos_c = lat_c.cos();
+
+        let b = (1. + e2 * cos_c.powi(4) / (1. - e2)).sqrt();
+        let a = ell.semimajor_axis * b * kc * (1. - e2).sqrt() / (1. - e2 * sin_c.powi(2));
+        let t0 = (-geodetic2isometric(lat_c, ell, false)?).exp();
+        let d = (b * (1. - e2).sqrt() / (cos_c * (1. - e2 * sin_c.powi(2)).sqrt())).max(1.);
+        let f = d + (d.powi(2) - 1.).sqrt() * lat_c.signum();
+        let h = f * t0.powf(b);
+        let g = (f - 1. / f) / 2.;
+
+        let gamma0 = (azimuth.sin() / d).asin();
+        let lon0 = lon_c - (g * gamma0.tan()).asin() / b;
+
+        let uc = if !centre {
+            0.
+        } else if azimuth.cos().abs() < 1e-12 {
+            a * (lon_c - lon0)
+        } else {
+            (a / b * ((d.powi(2) - 1.).sqrt() / azimuth.cos()).atan()).abs() * lat_c.signum()
+        };
+
+        if !lon0.is_finite() || !uc.is_finite() {
+            return None;
+        }
+
+        Some(HotineObliqueMercator {
+            lat_c,
+            lon_c,
+            azimuth,
+            rectified,
+            kc,
+            false_easting,
+            false_northing,
+            ellipsoid: ell.clone(),
+            a,
+            b,
+            h,
+            gamma0,
+            lon0,
+            uc,
+        })
+    }
+
+    pub fn variant_a(
+        lat_c: f64,
+        lon_c: f64,
+        azimuth: f64,
+        rectified: f64,
+        kc: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        Self::build(
+            lat_c,
+            lon_c,
+            azimuth,
+            rectified,
+            kc,
+            false_easting,
+            false_northing,
+            false,
+            ell,
+            deg,
+        )
+    }
+
+    pub fn variant_b(
+        lat_c: f64,
+        lon_c: f64,
+        azimuth: f64,
+        rectified: f64,
+        kc: f64,
+        easting_c: f64,
+        northing_c: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        Self::build(
+            lat_c, lon_c, azimuth, rectified, kc, easting_c, northing_c, true, ell, deg,
+        )
+    }
+
+    fn rectified_uv(&self, lat: f64, lon: f64) -> Option<(f64, f64)> {
+        let dlon = wrap_lon(lon - self.lon0);
+
+        let (u, v) = if FRAC_PI_2 - lat.abs() < 1e-12 {
+            let v = self.a / self.b * (FRAC_PI_4 - lat.signum() * self.gamma0 / 2.).tan().ln();
+            (self.a * lat / self.b, v)
+        } else {
+            let t = (-geodetic2isometric(lat, &self.ellipsoid, false)?).exp();
+            let q = self.h / t.powf(self.b);
+            let s = (q - 1. / q) / 2.;
+            let t = (q + 1. / q) / 2.;
+            let v = (self.b * dlon).sin();
+            let u = (-v * self.gamma0.cos() + s * self.gamma0.sin()) / t;
+
+            if u.abs() >= 1. {
+                return None;
+            }
+
+            let v = self.a * ((1. - u) / (1. + u)).ln() / (2. * self.b);
+            let u = self.a / self.b
+                * (s * self.gamma0.cos() + (self.b * dlon).sin() * self.gamma0.sin())
+                    .atan2((self.b * dlon).cos());
+
+            (u, v)
+        };
+
+        Some((u - self.uc, v))
+    }
+
+    pub fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        if lat.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        let (u, v) = self.rectified_uv(lat, lon)?;
+        let (sin_g, cos_g) = self.rectified.sin_cos();
+
+        Some((
+            v * cos_g + u * sin_g + self.false_easting,
+            u * cos_g - v * sin_g + self.false_northing,
+        ))
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)> {
+        let (sin_g, cos_g) = self.rectified.sin_cos();
+        let dx = x - self.false_easting;
+        let dy = y - self.false_northing;
+
+        let v = dx * cos_g - dy * sin_g;
+        let u = dy * cos_g + dx * sin_g + self.uc;
+
+        let q = (-self.b * v / self.a).exp();
+        let s = (q - 1. / q) / 2.;
+        let t = (q + 1. / q) / 2.;
+        let vv = (self.b * u / self.a).sin();
+        let uu = (vv * self.gamma0.cos() + s * self.gamma0.sin()) / t;
+
+        let (lat, lon) = if (uu.abs() - 1.).abs() < 1e-14 {
+            (FRAC_PI_2 * uu.signum(), self.lon0)
+        } else {
+            let t = (self.h / ((1. + uu) / (1. - uu)).sqrt()).powf(1. / self.b);
+            let chi = FRAC_PI_2 - 2. * t.atan();
+            let lat = conformal2geodetic(chi, &self.ellipsoid, false)?;
+            let lon = self.lon0
+                - (s * self.gamma0.cos() - vv * self.gamma0.sin())
+                    .atan2((self.b * u / self.a).cos())
+                    / self.b;
+
+            (lat, wrap_lon(lon))
+        };
+
+        let lat = if deg { lat.to_degrees() } else { lat };
+        let lon = if deg { lon.to_degrees() } else { lon };
+
+        Some((lat, lon))
+    }
+
+    pub fn scale(&self, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        if lat.abs() >= FRAC_PI_2 {
+            return None;
+        }
+
+        let (u, _) = self.rectified_uv(lat, lon)?;
+        let u = u + self.uc;
+        let e = self.ellipsoid.eccentricity;
+
+        Some(
+            self.a * (self.b * u / self.a).cos() * (1. - (e * lat.sin()).powi(2)).sqrt()
+                / (self.ellipsoid.semimajor_axis
+                    * lat.cos()
+                    * (self.b * wrap_lon(lon - self.lon0)).cos()),
+        )
+    }
+}
@@ -0,0 +1,444 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use crate::{Crs, Ellipsoid, Geoid, Helmert, TransverseMercator};
+
+const SQUARE: f64 = 100_000.;
+const SPACING: f64 = 1000.;
+const IRISH_LETTERS: &[u8] = b"ABCDEFGHJKLMNOPQRSTUVWXYZ";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NationalGrid {
+    British,
+    Irish,
+}
+
+impl NationalGrid {
+    pub fn ellipsoid(&self) -> Ellipsoid {
+        match self {
+            NationalGrid::British => Ellipsoid::airy(),
+            NationalGrid::Irish => Ellipsoid::airy_modified(),
+        }
+    }
+
+    pub fn projection(&self) -> TransverseMercator {
+        let ell = self.ellipsoid();
+
+        match self {
+            NationalGrid::British => {
+                TransverseMercator::new(49., -2., 0.9996012717, 400_000., -100_000., &ell, true)
+            }
+            NationalGrid::Irish => {
+                TransverseMercator::new(53.5, -8., 1.000035, 200_000., 250_000., &ell, true)
+            }
+        }
+        .unwrap()
+    }
+
+    pub fn towgs84(&self) -> Helmert {
+        match self {
+            NationalGrid::British => {
+                Helmert::new(446.448, -125.157, 542.06, 0.15, 0.247, 0.842, -20.489)
+            }
+            NationalGrid::Irish => Helmert::new(482.5, -130.6, 564.6, -1.042, -0.214, -0.631, 8.15),
+        }
+    }
+
+    pub fn crs(&self) -> Crs {
+        Crs::new(Box::new(self.projection())).with_towgs84(self.towgs84())
+    }
+
+    fn extent(&self) -> (f64, f64) {
+        match self {
+            NationalGrid::British => (700_000., 1_300_000.),
+            NationalGrid::Irish => (500_000., 500_000.),
+        }
+    }
+
+    fn letters(&self, easting: f64, northing: f64) -> Option<String> {
+        let (width, height) = self.extent();
+
+        if !(0. ..width).contains(&easting) || !(0. ..height).contains(&northing) {
+            return None;
+        }
+
+        let e = (easting / SQUARE) as u8;
+        let n = (northing / SQUARE) as u8;
+
+        let skip_i = |l: u8| if l > 7 { l + 1 } else { l };
+
+        match self {
+            NationalGrid::British => {
+                let l1 = (19 - n) - (19 - n) % 5 + (e + 10) / 5;
+                let l2 = (19 - n) * 5 % 25 + e % 5;
+
+                Some(format!(
+                    "{}{}",
+                    (b'A' + skip_i(l1)) as char,
+                    (b'A' + skip_i(l2)) as char
+                ))
+            }
+            NationalGrid::Irish => {
+                Some((IRISH_LETTERS[((4 - n) * 5 + e) as usize] as char).to_string())
+            }
+        }
+    }
+
+    fn square(&self, letters: &[char]) -> Option<(f64, f64)> {
+        let index = |c: char| -> Option<i32> {
+            IRISH_LETTERS
+                .iter()
+                .position(|&l| l == c as u8)
+                .map(|i| i as i32)
+        };
+
+        let (e, n) = match (self, letters) {
+            (NationalGrid::British, &[c1, c2]) => {
+                let (l1, l2) = (index(c1)?, index(c2)?);
+
+                (
+                    (l1 - 2).rem_euclid(5) * 5 + l2 % 5,
+                    (19 - l1 / 5 * 5) - l2 / 5,
+                )
+            }
+            (NationalGrid::Irish, &[c]) => {
+                let l = index(c)?;
+
+                (l % 5, 4 - l / 5)
+            }
+            _ => return None,
+        };
+
+        let (e, n) = (e as f64 * SQUARE, n as f64 * SQUARE);
+        let (width, height) = self.extent();
+
+        if !(0. ..width).contains(&e) || !(0. ..height).contains(&n) {
+            return None;
+        }
+
+        Some((e, n))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridRef {
+    pub grid: NationalGrid,
+    pub easting: u32,
+    pub northing: u32,
+    pub precision: u8,
+}
+
+impl GridRef {
+    pub fn new(grid: NationalGrid, easting: f64, northing: f64, precision: u8) -> Option<GridRef> {
+        if precision > 5 {
+            return None;
+        }
+
+        grid.letters(easting, northing)?;
+
+        let scale = 10u32.pow(5 - precision as u32);
+        let truncate = |v: f64| v.floor() as u32 / scale * scale;
+
+        Some(GridRef {
+            grid,
+            easting: truncate(easting),
+            northing: truncate(northing),
+            precision,
+        })
+    }
+
+    pub fn parse(s: &str) -> Option<GridRef> {
+        let s: Vec<char> = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+
+        let letters = s.iter().take_while(|c| c.is_ascii_alphabetic()).count();
+
+        let grid = match letters {
+            1 => NationalGrid::Irish,
+            2 => NationalGrid::British,
+            _ => return None,
+        };
+
+        let (e, n) = grid.square(&s[..letters])?;
+        let rest = &s[letters..];
+
+        if !rest.len().is_multiple_of(2)
+            || rest.len() > 10
+            || !rest.iter().all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let precision = (rest.len() / 2) as u8;
+        let scale = 10u32.pow(5 - precision as u32);
+
+        let parse = |d: &[char]| -> Option<u32> {
+            if d.is_empty() {
+                Some(0)
+            } else {
+                d.iter().collect::<String>().parse::<u32>().ok()
+            }
+        };
+
+        let easting = parse(&rest[..precision as usize])? * scale;
+        let northing = parse(&rest[precision as usize..])? * scale;
+
+        Some(GridRef {
+            grid,
+            easting: e as u32 + easting,
+            northing: n as u32 + northing,
+            precision,
+        })
+    }
+
+    pub fn cell_size(&self) -> f64 {
+        10f64.powi(5 - self.precision as i32)
+    }
+
+    pub fn centre(&self) -> (f64, f64) {
+        let half = self.cell_size() / 2.;
+
+        (self.easting as f64 + half, self.northing as f64 + half)
+    }
+}
+
+impl fmt::Display for GridRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let letters = self
+            .grid
+            .letters(self.easting as f64, self.northing as f64)
+            .ok_or(fmt::Error)?;
+
+        write!(f, "{}", letters)?;
+
+        if self.precision > 0 {
+            let p = self.precision as usize;
+            let scale = 10u32.pow(5 - self.precision as u32);
+
+            write!(
+                f,
+                " {:0p$} {:0p$}",
+                self.easting % SQUARE as u32 / scale,
+                self.northing % SQUARE as u32 / scale,
+                p = p
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn geodetic2gridref(
+    lat: f64,
+    lon: f64,
+    grid: NationalGrid,
+    precision: u8,
+    deg: bool,
+) -> Option<GridRef> {
+    let (easting, northing) = grid.projection().forward(lat, lon, deg)?;
+
+    GridRef::new(grid, easting, northing, precision)
+}
+
+pub fn gridref2geodetic(gridref: &GridRef, deg: bool) -> Option<(f64, f64)> {
+    let (easting, northing) = gridref.centre();
+
+    gridref.grid.projection().inverse(easting, northing, deg)
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.to_string())
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ostn15 {
+    pub west: f64,
+    pub south: f64,
+    pub ncols: usize,
+    pub nrows: usize,
+    data: Vec<[f32; 3]>,
+    geoid: Vec<bool>,
+}
+
+impl Ostn15 {
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ostn15::from_csv(BufReader::new(File::open(path)?))
+    }
+
+    pub fn from_csv<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut records = Vec::new();
+
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
+
+            if line.trim().is_empty() || (i == 0 && fields[0].parse::<f64>().is_err()) {
+                continue;
+            }
+
+            if fields.len() < 6 {
+                return Err(invalid_data(&format!("truncated record on line {}", i + 1)));
+            }
+
+            let mut values = [0.; 7];
+            for (value, field) in values.iter_mut().zip(&fields) {
+                *value = field
+                    .parse()
+                    .map_err(|_| invalid_data(&format!("invalid number `{}`", field)))?;
+            }
+
+            if fields.len() < 7 {
+                values[6] = 1.;
+            }
+
+            records.push(values);
+        }
+
+        if records.is_empty() {
+            return Err(invalid_data("empty ostn15 file"));
+        }
+
+        let fold = |i: usize, f: fn(f64, f64) -> f64, init: f64| {
+            records.iter().map(|r| r[i]).fold(init, f)
+        };
+
+        let (west, east) = (
+            fold(1, f64::min, f64::INFINITY),
+            fold(1, f64::max, -f64::INFINITY),
+        );
+        let (south, north) = (
+            fold(2, f64::min, f64::INFINITY),
+            fold(2, f64::max, -f64::INFINITY),
+        );
+
+        let ncols = ((east - west) / SPACING).round() as usize + 1;
+        let nrows = ((north - south) / SPACING).round() as usize + 1;
+
+        if ncols < 2 || nrows < 2 {
+            return Err(invalid_data("ostn15 grid needs at least 2x2 nodes"));
+        }
+
+        let mut data = vec![[f32::NAN; 3]; ncols * nrows];
+        let mut geoid = vec![false; ncols * nrows];
+
+        for r in &records {
+            let col = (r[1] - west) / SPACING;
+            let row = (r[2] - south) / SPACING;
+
+            if (col - col.round()).abs() > 1e-6 || (row - row.round()).abs() > 1e-6 {
+                return Err(invalid_data("ostn15 node off the 1 km grid"));
+            }
+
+            let k = row.round() as usize * ncols + col.round() as usize;
+            data[k] = [r[3] as f32, r[4] as f32, r[5] as f32];
+            geoid[k] = r[6] != 0.;
+        }
+
+        Ok(Ostn15 {
+            west,
+            south,
+            ncols,
+            nrows,
+            data,
+            geoid,
+        })
+    }
+
+    pub fn etrs89_projection() -> TransverseMercator {
+        TransverseMercator::new(
+            49.,
+            -2.,
+            0.9996012717,
+            400_000.,
+            -100_000.,
+            &Ellipsoid::grs80(),
+            true,
+        )
+        .unwrap()
+    }
+
+    fn cell(&self, x: f64, y: f64) -> Option<(usize, usize, f64, f64)> {
+        let col = (x - self.west) / SPACING;
+        let row = (y - self.south) / SPACING;
+
+        if col < 0. || row < 0. || col > (self.ncols - 1) as f64 || row > (self.nrows - 1) as f64 {
+            return None;
+        }
+
+        let c = (col.floor() as usize).min(self.ncols - 2);
+        let r = (row.floor() as usize).min(self.nrows - 2);
+
+        Some((r, c, col - c as f64, row - r as f64))
+    }
+
+    pub fn shift(&self, x: f64, y: f64) -> Option<(f64, f64, f64)> {
+        let (r, c, tx, ty) = self.cell(x, y)?;
+
+        let node = |r: usize, c: usize| self.data[r * self.ncols + c];
+        let (v00, v01, v10, v11) = (
+            node(r, c),
+            node(r, c + 1),
+            node(r + 1, c),
+            node(r + 1, c + 1),
+        );
+
+        let value = |i: usize| {
+            (1. - ty) * ((1. - tx) * v00[i] as f64 + tx * v01[i] as f64)
+                + ty * ((1. - tx) * v10[i] as f64 + tx * v11[i] as f64)
+        };
+
+        let shift = (value(0), value(1), value(2));
+
+        if shift.0.is_nan() || shift.1.is_nan() || shift.2.is_nan() {
+            None
+        } else {
+            Some(shift)
+        }
+    }
+
+    pub fn etrs892osgb(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        let (x, y) = Ostn15::etrs89_projection().forward(lat, lon, deg)?;
+        let (se, sn, _) = self.shift(x, y)?;
+
+        Some((x + se, y + sn))
+    }
+
+    pub fn osgb2etrs89(&self, easting: f64, northing: f64, deg: bool) -> Option<(f64, f64)> {
+        let (se, sn, _) = self.shift(easting, northing)?;
+        let (mut x, mut y) = (easting - se, northing - sn);
+
+        for _ in 0..20 {
+            let (se, sn, _) = self.shift(x, y)?;
+            let (x1, y1) = (easting - se, northing - sn);
+            let moved = (x1 - x).hypot(y1 - y);
+
+            x = x1;
+            y = y1;
+
+            if moved < 1e-4 {
+                break;
+            }
+        }
+
+        Ostn15::etrs89_projection().inverse(x, y, deg)
+    }
+}
+
+impl Geoid for Ostn15 {
+    fn undulation(&self, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+        let (x, y) = Ostn15::etrs89_projection().forward(lat, lon, deg)?;
+        let (r, c, _, _) = self.cell(x, y)?;
+
+        let valid = |r: usize, c: usize| self.geoid[r * self.ncols + c];
+        if !(valid(r, c) && valid(r, c + 1) && valid(r + 1, c) && valid(r + 1, c + 1)) {
+            return None;
+        }
+
+        Some(self.shift(x, y)?.2)
+    }
+}
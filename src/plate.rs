@@ -0,0 +1,172 @@
+use std::f64::consts::PI;
+
+use crate::{ecef2enuv, ecef2geodetic, geodetic2ecef, Ellipsoid};
+
+const MAS: f64 = PI / (180. * 3600. * 1000.);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EulerPole {
+    pub wx: f64,
+    pub wy: f64,
+    pub wz: f64,
+}
+
+impl EulerPole {
+    pub fn new(wx: f64, wy: f64, wz: f64) -> Self {
+        EulerPole { wx, wy, wz }
+    }
+
+    pub fn from_mas(wx: f64, wy: f64, wz: f64) -> Self {
+        EulerPole::new(wx * MAS, wy * MAS, wz * MAS)
+    }
+
+    pub fn from_pole(lat: f64, lon: f64, rate: f64, deg: bool) -> Self {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+        let rate = rate.to_radians() / 1e6;
+
+        EulerPole::new(
+            rate * lat.cos() * lon.cos(),
+            rate * lat.cos() * lon.sin(),
+            rate * lat.sin(),
+        )
+    }
+
+    pub fn rate(&self) -> f64 {
+        self.wx.hypot(self.wy).hypot(self.wz)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlateModel {
+    NnrMorvel56,
+    Itrf2014,
+}
+
+const ITRF2014: [(&str, &str, f64, f64, f64); 11] = [
+    ("ANTA", "antarctica", -0.248, -0.324, 0.675),
+    ("ARAB", "arabia", 1.154, -0.136, 1.444),
+    ("AUST", "australia", 1.510, 1.182, 1.215),
+    ("EURA", "eurasia", -0.085, -0.531, 0.770),
+    ("INDI", "india", 1.154, -0.005, 1.454),
+    ("NAZC", "nazca", -0.333, -1.544, 1.623),
+    ("NOAM", "north america", 0.024, -0.694, -0.063),
+    ("NUBI", "nubia", 0.099, -0.614, 0.733),
+    ("PCFC", "pacific", -0.409, 1.047, -2.169),
+    ("SOAM", "south america", -0.270, -0.301, -0.140),
+    ("SOMA", "somalia", -0.121, -0.794, 0.884),
+];
+
+const NNR_MORVEL56: [(&str, &str, f64, f64, f64); 25] = [
+    ("AM", "amur", 63.17, -122.82, 0.297),
+    ("AN", "antarctica", 65.42, -118.11, 0.250),
+    ("AR", "arabia", 48.88, -8.49, 0.559),
+    ("AU", "australia", 33.86, 37.94, 0.632),
+    ("CA", "caribbean", 35.20, -92.62, 0.286),
+    ("CO", "cocos", 26.93, -124.31, 1.198),
+    ("CP", "capricorn", 44.44, 23.09, 0.608),
+    ("EU", "eurasia", 48.85, -106.50, 0.223),
+    ("IN", "india", 50.37, -3.29, 0.544),
+    ("JF", "juan de fuca", -38.31, 60.04, 0.951),
+    ("LW", "lwandle", 51.89, -69.52, 0.286),
+    ("MQ", "macquarie", 49.19, 11.05, 1.144),
+    ("NA", "north america", -4.85, -80.64, 0.209),
+    ("NB", "nubia", 47.68, -68.44, 0.292),
+    ("NZ", "nazca", 46.23, -101.06, 0.696),
+    ("PA", "pacific", -63.58, 114.70, 0.651),
+    ("PS", "philippine sea", -46.02, -31.36, 0.910),
+    ("RI", "rivera", 20.25, -107.29, 4.536),
+    ("SA", "south america", -22.62, -112.83, 0.109),
+    ("SC", "scotia", 22.52, -106.15, 0.146),
+    ("SM", "somalia", 49.95, -84.52, 0.339),
+    ("SR", "sur", 10.32, -111.55, 0.214),
+    ("SU", "sunda", 50.06, -95.02, 0.337),
+    ("SW", "sandwich", -29.94, -36.87, 1.362),
+    ("YZ", "yangtze", 63.03, -116.62, 0.334),
+];
+
+impl PlateModel {
+    pub fn get(name: &str) -> Option<PlateModel> {
+        match name.to_lowercase().as_str() {
+            "nnr-morvel56" | "nnr_morvel56" | "morvel56" => Some(PlateModel::NnrMorvel56),
+            "itrf2014" | "itrf2014-pmm" | "itrf2014_pmm" => Some(PlateModel::Itrf2014),
+            _ => None,
+        }
+    }
+
+    pub fn plates(&self) -> Vec<&'static str> {
+        match self {
+            PlateModel::NnrMorvel56 => NNR_MORVEL56.iter().map(|p| p.0).collect(),
+            PlateModel::Itrf2014 => ITRF2014.iter().map(|p| p.0).collect(),
+        }
+    }
+
+    pub fn pole(&self, plate: &str) -> Option<EulerPole> {
+        let matches = |code: &str, name: &str| {
+            plate.eq_ignore_ascii_case(code) || plate.eq_ignore_ascii_case(name)
+        };
+
+        match self {
+            PlateModel::NnrMorvel56 => NNR_MORVEL56
+                .iter()
+                .find(|p| matches(p.0, p.1))
+                .map(|p| EulerPole::from_pole(p.2, p.3, p.4, true)),
+            PlateModel::Itrf2014 => ITRF2014
+                .iter()
+                .find(|p| matches(p.0, p.1))
+                .map(|p| EulerPole::from_mas(p.2, p.3, p.4)),
+        }
+    }
+}
+
+pub fn plate_velocity(x: f64, y: f64, z: f64, pole: &EulerPole) -> (f64, f64, f64) {
+    let vx = pole.wy * z - pole.wz * y;
+    let vy = pole.wz * x - pole.wx * z;
+    let vz = pole.wx * y - pole.wy * x;
+
+    (vx, vy, vz)
+}
+
+pub fn plate_velocity_enu(
+    lat: f64,
+    lon: f64,
+    h: f64,
+    pole: &EulerPole,
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64, f64)> {
+    let (x, y, z) = geodetic2ecef(lat, lon, h, ell, deg)?;
+    let (vx, vy, vz) = plate_velocity(x, y, z, pole);
+
+    Some(ecef2enuv(vx, vy, vz, lat, lon, deg))
+}
+
+pub fn plate_propagate(
+    x: f64,
+    y: f64,
+    z: f64,
+    pole: &EulerPole,
+    epoch0: f64,
+    epoch1: f64,
+) -> (f64, f64, f64) {
+    let dt = epoch1 - epoch0;
+    let (vx, vy, vz) = plate_velocity(x, y, z, pole);
+
+    (x + vx * dt, y + vy * dt, z + vz * dt)
+}
+
+pub fn plate_propagate_geodetic(
+    lat: f64,
+    lon: f64,
+    h: f64,
+    pole: &EulerPole,
+    epoch0: f64,
+    epoch1: f64,
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64, f64)> {
+    let (x, y, z) = geodetic2ecef(lat, lon, h, ell, deg)?;
+    let (x, y, z) = plate_propagate(x, y, z, pole, epoch0, epoch1);
+
+    Some(ecef2geodetic(x, y, z, ell, deg))
+}
@@ -0,0 +1,203 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use crate::rcurve::parallel_radius;
+use crate::tmerc::wrap_lon;
+use crate::{conformal2geodetic, geodetic2conformal, Ellipsoid};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolarStereographic {
+    pub north: bool,
+    pub lon0: f64,
+    pub k0: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+    pub ellipsoid: Ellipsoid,
+    origin: f64,
+}
+
+fn pole_factor(e: f64) -> f64 {
+    ((1. + e).powf(1. + e) * (1. - e).powf(1. - e)).sqrt()
+}
+
+fn parallel_scale(lat: f64, ell: &Ellipsoid) -> (f64, f64) {
+    let chi = geodetic2conformal(lat.abs(), ell, false).unwrap_or(FRAC_PI_2);
+
+    (parallel_radius(lat, ell), (FRAC_PI_4 - chi / 2.).tan())
+}
+
+impl PolarStereographic {
+    pub fn variant_a(
+        north: bool,
+        lon0: f64,
+        k0: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let lon0 = if deg { lon0.to_radians() } else { lon0 };
+
+        if k0 <= 0. {
+            return None;
+        }
+
+        Some(PolarStereographic {
+            north,
+            lon0,
+            k0,
+            false_easting,
+            false_northing,
+            ellipsoid: ell.clone(),
+            origin: 0.,
+        })
+    }
+
+    pub fn variant_b(
+        lat_ts: f64,
+        lon0: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let lat_ts = if deg { lat_ts.to_radians() } else { lat_ts };
+
+        if lat_ts == 0. || lat_ts.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        let k0 = if lat_ts.abs() == FRAC_PI_2 {
+            1.
+        } else {
+            let (m, t) = parallel_scale(lat_ts, ell);
+            m * pole_factor(ell.eccentricity) / (2. * t)
+        };
+
+        Self::variant_a(
+            lat_ts > 0.,
+            lon0,
+            k0,
+            false_easting,
+            false_northing,
+            ell,
+            deg,
+        )
+    }
+
+    pub fn variant_c(
+        lat_ts: f64,
+        lon0: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let mut stereo = Self::variant_b(lat_ts, lon0, false_easting, false_northing, ell, deg)?;
+
+        let lat_ts = if deg { lat_ts.to_radians() } else { lat_ts };
+        let (m, _) = parallel_scale(lat_ts, ell);
+        let rho = ell.semimajor_axis * m;
+
+        stereo.origin = if stereo.north { rho } else { -rho };
+
+        Some(stereo)
+    }
+
+    fn rho(&self, lat: f64) -> Option<f64> {
+        let lat = if self.north { lat } else { -lat };
+
+        let chi = geodetic2conformal(lat, &self.ellipsoid, false)?;
+        let t = (FRAC_PI_4 - chi / 2.).tan().max(0.);
+
+        Some(
+            2. * self.ellipsoid.semimajor_axis * self.k0 * t
+                / pole_factor(self.ellipsoid.eccentricity),
+        )
+    }
+
+    pub fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        if lat.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        let rho = self.rho(lat)?;
+        let theta = lon - self.lon0;
+
+        let x = self.false_easting + rho * theta.sin();
+        let y = if self.north {
+            self.false_northing + self.origin - rho * theta.cos()
+        } else {
+            self.false_northing + self.origin + rho * theta.cos()
+        };
+
+        Some((x, y))
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)> {
+        let dx = x - self.false_easting;
+        let dy = y - self.false_northing - self.origin;
+
+        let rho = dx.hypot(dy);
+        let t = rho * pole_factor(self.ellipsoid.eccentricity)
+            / (2. * self.ellipsoid.semimajor_axis * self.k0);
+        let chi = FRAC_PI_2 - 2. * t.atan();
+
+        let lat = conformal2geodetic(chi, &self.ellipsoid, false)?;
+
+        let (lat, theta) = if self.north {
+            (lat, dx.atan2(-dy))
+        } else {
+            (-lat, dx.atan2(dy))
+        };
+
+        let lon = wrap_lon(self.lon0 + theta);
+
+        let lat = if deg { lat.to_degrees() } else { lat };
+        let lon = if deg { lon.to_degrees() } else { lon };
+
+        Some((lat, lon))
+    }
+
+    pub fn convergence(&self, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        if lat.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        let gamma = if self.north {
+            lon - self.lon0
+        } else {
+            self.lon0 - lon
+        };
+        let gamma = wrap_lon(gamma);
+
+        Some(if deg { gamma.to_degrees() } else { gamma })
+    }
+
+    pub fn scale(&self, lat: f64, _lon: f64, deg: bool) -> Option<f64> {
+        let lat = if deg { lat.to_radians() } else { lat };
+
+        if lat.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        let colat = if self.north {
+            FRAC_PI_2 - lat
+        } else {
+            FRAC_PI_2 + lat
+        };
+
+        if colat < 1e-9 {
+            return Some(self.k0);
+        }
+
+        let (m, _) = parallel_scale(lat, &self.ellipsoid);
+
+        Some(self.rho(lat)? / (self.ellipsoid.semimajor_axis * m))
+    }
+}
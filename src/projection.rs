@@ -0,0 +1,449 @@
+use std::collections::HashMap;
+use std::f64::consts::FRAC_PI_2;
+
+use crate::{
+    ecef2geodetic, geodetic2ecef, parallel, ups_projection, utm_projection, AlbersEqualArea,
+    AzimuthalEquidistant, CassiniSoldner, Ellipsoid, EqualEarth, Equirectangular, Gnomonic,
+    Helmert, HotineObliqueMercator, LambertAzimuthalEqualArea, LambertConformalConic, Mercator,
+    PolarStereographic, Robinson, Sinusoidal, TransverseMercator,
+};
+
+const STEP: f64 = 1e-5;
+
+pub trait Projection {
+    fn ellipsoid(&self) -> &Ellipsoid;
+
+    fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)>;
+
+    fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)>;
+
+    fn scale(&self, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        if FRAC_PI_2 - lat.abs() < STEP {
+            return None;
+        }
+
+        let (x1, y1) = self.forward(lat, lon - STEP, false)?;
+        let (x2, y2) = self.forward(lat, lon + STEP, false)?;
+
+        let radius = parallel(lat, self.ellipsoid(), false)?;
+
+        Some((x2 - x1).hypot(y2 - y1) / (2. * STEP * radius))
+    }
+
+    fn convergence(&self, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        if lat.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        let (x1, y1) = self.forward(lat - STEP, lon, false)?;
+        let (x2, y2) = self.forward(lat + STEP, lon, false)?;
+
+        let gamma = -(x2 - x1).atan2(y2 - y1);
+
+        Some(if deg { gamma.to_degrees() } else { gamma })
+    }
+}
+
+macro_rules! projection {
+    ($name:ty $(, $method:ident)*) => {
+        impl Projection for $name {
+            fn ellipsoid(&self) -> &Ellipsoid {
+                &self.ellipsoid
+            }
+
+            fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+                <$name>::forward(self, lat, lon, deg)
+            }
+
+            fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)> {
+                <$name>::inverse(self, x, y, deg)
+            }
+
+            $(
+                fn $method(&self, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+                    <$name>::$method(self, lat, lon, deg)
+                }
+            )*
+        }
+    };
+}
+
+projection!(AlbersEqualArea, scale, convergence);
+projection!(AzimuthalEquidistant, scale, convergence);
+projection!(CassiniSoldner);
+projection!(EqualEarth);
+projection!(Equirectangular);
+projection!(Gnomonic);
+projection!(HotineObliqueMercator, scale);
+projection!(LambertAzimuthalEqualArea);
+projection!(LambertConformalConic, scale, convergence);
+projection!(Mercator, scale, convergence);
+projection!(PolarStereographic, scale, convergence);
+projection!(Robinson);
+projection!(Sinusoidal);
+projection!(TransverseMercator, scale, convergence);
+
+fn ellipsoid_alias(name: &str) -> &str {
+    match name {
+        "intl" => "international1924",
+        "clrk66" => "clarke1866",
+        "clrk80" => "clarke1860",
+        "krass" => "krassovsky1940",
+        "evrst30" => "everest1830",
+        "aust_sa" => "australian",
+        "mod_airy" => "airy_modified",
+        _ => name,
+    }
+}
+
+fn datum_alias(name: &str) -> Option<(&'static str, &'static str)> {
+    match name {
+        "wgs84" => Some(("wgs84", "0,0,0")),
+        "nad83" => Some(("grs80", "0,0,0")),
+        "osgb36" => Some(("airy", "446.448,-125.157,542.06,0.15,0.247,0.842,-20.489")),
+        _ => None,
+    }
+}
+
+struct Params(HashMap<String, Option<String>>);
+
+impl Params {
+    fn parse(s: &str) -> Option<Params> {
+        let mut params = HashMap::new();
+
+        for token in s.split_whitespace() {
+            let token = token.strip_prefix('+')?;
+
+            let (key, value) = match token.split_once('=') {
+                Some((key, value)) => (key, Some(value.to_string())),
+                None => (token, None),
+            };
+
+            if key.is_empty() || params.insert(key.to_string(), value).is_some() {
+                return None;
+            }
+        }
+
+        if let Some(datum) = params.remove("datum") {
+            let (ellps, towgs84) = datum_alias(&datum?.to_ascii_lowercase())?;
+
+            if ["ellps", "R", "a", "towgs84"]
+                .iter()
+                .any(|key| params.contains_key(*key))
+            {
+                return None;
+            }
+
+            params.insert("ellps".to_string(), Some(ellps.to_string()));
+            params.insert("towgs84".to_string(), Some(towgs84.to_string()));
+        }
+
+        Some(Params(params))
+    }
+
+    fn flag(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+
+    fn text(&self, key: &str) -> Option<&str> {
+        self.0.get(key)?.as_deref()
+    }
+
+    fn number(&self, key: &str) -> Option<Option<f64>> {
+        match self.0.get(key) {
+            None => Some(None),
+            Some(value) => Some(Some(value.as_deref()?.parse().ok()?)),
+        }
+    }
+
+    fn number_or(&self, key: &str, default: f64) -> Option<f64> {
+        Some(self.number(key)?.unwrap_or(default))
+    }
+
+    fn scale_factor(&self) -> Option<f64> {
+        match self.number("k_0")? {
+            Some(k0) => Some(k0),
+            None => self.number_or("k", 1.),
+        }
+    }
+
+    fn ellipsoid(&self) -> Option<Ellipsoid> {
+        if let Some(r) = self.number("R")? {
+            return Some(Ellipsoid::new(r, r, "Sphere", "sphere"));
+        }
+
+        if let Some(a) = self.number("a")? {
+            let b = match (self.number("b")?, self.number("rf")?, self.number("f")?) {
+                (Some(b), None, None) => b,
+                (None, Some(rf), None) => a * (1. - 1. / rf),
+                (None, None, Some(f)) => a * (1. - f),
+                (None, None, None) => a,
+                _ => return None,
+            };
+
+            return Some(Ellipsoid::new(a, b, "Custom", "custom"));
+        }
+
+        let name = self.text("ellps").unwrap_or("grs80").to_ascii_lowercase();
+
+        Ellipsoid::get(ellipsoid_alias(&name))
+    }
+}
+
+const KNOWN: [&str; 28] = [
+    "proj", "datum", "ellps", "R", "a", "b", "rf", "f", "lat_0", "lon_0", "lat_1", "lat_2",
+    "lat_ts", "k", "k_0", "x_0", "y_0", "zone", "south", "lonc", "alpha", "gamma", "no_uoff",
+    "towgs84", "units", "no_defs", "type", "wktext",
+];
+
+fn build(p: &Params, ell: &Ellipsoid) -> Option<Box<dyn Projection>> {
+    let lat0 = p.number_or("lat_0", 0.)?;
+    let lon0 = p.number_or("lon_0", 0.)?;
+    let fe = p.number_or("x_0", 0.)?;
+    let fn_ = p.number_or("y_0", 0.)?;
+
+    let projection: Box<dyn Projection> = match p.text("proj")? {
+        "tmerc" => Box::new(TransverseMercator::new(
+            lat0,
+            lon0,
+            p.scale_factor()?,
+            fe,
+            fn_,
+            ell,
+            true,
+        )?),
+        "utm" => {
+            let zone = p.text("zone")?.parse().ok()?;
+
+            Box::new(utm_projection(zone, !p.flag("south"), ell)?)
+        }
+        "ups" => Box::new(ups_projection(!p.flag("south"), ell)?),
+        "stere" => {
+            if lat0.abs() != 90. {
+                return None;
+            }
+
+            match p.number("lat_ts")? {
+                Some(lat_ts) if lat_ts.signum() == lat0.signum() => Box::new(
+                    PolarStereographic::variant_b(lat_ts, lon0, fe, fn_, ell, true)?,
+                ),
+                Some(_) => return None,
+                None => Box::new(PolarStereographic::variant_a(
+                    lat0 > 0.,
+                    lon0,
+                    p.scale_factor()?,
+                    fe,
+                    fn_,
+                    ell,
+                    true,
+                )?),
+            }
+        }
+        "lcc" => {
+            let lat1 = p.number("lat_1")?.unwrap_or(lat0);
+
+            match p.number("lat_2")? {
+                Some(lat2) if lat2 != lat1 => Box::new(LambertConformalConic::new_2sp(
+                    lat1, lat2, lat0, lon0, fe, fn_, ell, true,
+                )?),
+                _ => {
+                    let lat0 = match (p.number("lat_0")?, p.flag("lat_2")) {
+                        (Some(lat0), _) => lat0,
+                        (None, true) => 0.,
+                        (None, false) => lat1,
+                    };
+
+                    Box::new(
+                        LambertConformalConic::new_1sp(
+                            lat1,
+                            lon0,
+                            p.scale_factor()?,
+                            fe,
+                            fn_,
+                            ell,
+                            true,
+                        )?
+                        .with_origin(lat0, true)?,
+                    )
+                }
+            }
+        }
+        "aea" => {
+            let lat1 = p.number("lat_1")??;
+            let lat2 = p.number_or("lat_2", lat1)?;
+
+            Box::new(AlbersEqualArea::new(
+                lat1, lat2, lat0, lon0, fe, fn_, ell, true,
+            )?)
+        }
+        "laea" => Box::new(LambertAzimuthalEqualArea::new(
+            lat0, lon0, fe, fn_, ell, true,
+        )?),
+        "merc" => match p.number("lat_ts")? {
+            Some(lat_ts) => Box::new(Mercator::new_2sp(lat_ts, lon0, fe, fn_, ell, true)?),
+            None => Box::new(Mercator::new(lon0, p.scale_factor()?, fe, fn_, ell, true)?),
+        },
+        "webmerc" => Box::new(Mercator::new(
+            lon0,
+            p.scale_factor()?,
+            fe,
+            fn_,
+            &Mercator::web().ellipsoid,
+            true,
+        )?),
+        "aeqd" => Box::new(AzimuthalEquidistant::new(lat0, lon0, fe, fn_, ell, true)?),
+        "gnom" => {
+            if fe != 0. || fn_ != 0. {
+                return None;
+            }
+
+            Box::new(Gnomonic::new(lat0, lon0, ell, true)?)
+        }
+        "omerc" => {
+            let lonc = p.number("lonc")??;
+            let alpha = p.number("alpha")??;
+            let gamma = p.number_or("gamma", alpha)?;
+            let k0 = p.scale_factor()?;
+
+            if p.flag("no_uoff") {
+                Box::new(HotineObliqueMercator::variant_a(
+                    lat0, lonc, alpha, gamma, k0, fe, fn_, ell, true,
+                )?)
+            } else {
+                Box::new(HotineObliqueMercator::variant_b(
+                    lat0, lonc, alpha, gamma, k0, fe, fn_, ell, true,
+                )?)
+            }
+        }
+        "eqc" => Box::new(Equirectangular::new(
+            p.number_or("lat_ts", 0.)?,
+            lon0,
+            fe,
+            fn_,
+            ell,
+            true,
+        )?),
+        "cass" => Box::new(CassiniSoldner::new(lat0, lon0, fe, fn_, ell, true)?),
+        "sinu" => Box::new(Sinusoidal::new(lon0, fe, fn_, ell, true)?),
+        "eqearth" => Box::new(EqualEarth::new(lon0, fe, fn_, ell, true)?),
+        "robin" => Box::new(Robinson::new(lon0, fe, fn_, ell, true)?),
+        _ => return None,
+    };
+
+    Some(projection)
+}
+
+pub struct Crs {
+    pub projection: Box<dyn Projection>,
+    pub towgs84: Option<Helmert>,
+}
+
+impl Crs {
+    pub fn new(projection: Box<dyn Projection>) -> Self {
+        Crs {
+            projection,
+            towgs84: None,
+        }
+    }
+
+    pub fn with_towgs84(mut self, towgs84: Helmert) -> Self {
+        self.towgs84 = Some(towgs84);
+        self
+    }
+
+    pub fn parse(s: &str) -> Option<Crs> {
+        let p = Params::parse(s)?;
+
+        if p.0.keys().any(|key| !KNOWN.contains(&key.as_str())) {
+            return None;
+        }
+
+        if !matches!(p.text("units"), None | Some("m")) {
+            return None;
+        }
+
+        let ell = p.ellipsoid()?;
+        let projection = build(&p, &ell)?;
+
+        let towgs84 = match p.text("towgs84") {
+            Some(values) => {
+                let values = values
+                    .split(',')
+                    .map(|v| v.trim().parse().ok())
+                    .collect::<Option<Vec<f64>>>()?;
+
+                Some(Helmert::from_towgs84(&values)?)
+            }
+            None if p.flag("towgs84") => return None,
+            None => None,
+        };
+
+        Some(Crs {
+            projection,
+            towgs84,
+        })
+    }
+
+    pub fn ellipsoid(&self) -> &Ellipsoid {
+        self.projection.ellipsoid()
+    }
+}
+
+fn same_ellipsoid(a: &Ellipsoid, b: &Ellipsoid) -> bool {
+    a.semimajor_axis == b.semimajor_axis && a.semiminor_axis == b.semiminor_axis
+}
+
+fn datum_shift(from: &Crs, to: &Crs, lat: f64, lon: f64) -> Option<(f64, f64)> {
+    if from.towgs84 == to.towgs84 && same_ellipsoid(from.ellipsoid(), to.ellipsoid()) {
+        return Some((lat, lon));
+    }
+
+    let (mut x, mut y, mut z) = geodetic2ecef(lat, lon, 0., from.ellipsoid(), false)?;
+
+    if let Some(helmert) = &from.towgs84 {
+        (x, y, z) = helmert.forward(x, y, z);
+    }
+
+    if let Some(helmert) = &to.towgs84 {
+        (x, y, z) = helmert.inverse(x, y, z);
+    }
+
+    let (lat, lon, _) = ecef2geodetic(x, y, z, to.ellipsoid(), false);
+
+    Some((lat, lon))
+}
+
+pub struct Pipeline {
+    pub source: Crs,
+    pub target: Crs,
+}
+
+impl Pipeline {
+    pub fn new(source: Crs, target: Crs) -> Self {
+        Pipeline { source, target }
+    }
+
+    pub fn parse(source: &str, target: &str) -> Option<Pipeline> {
+        Some(Pipeline::new(Crs::parse(source)?, Crs::parse(target)?))
+    }
+
+    pub fn forward(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let (lat, lon) = self.source.projection.inverse(x, y, false)?;
+        let (lat, lon) = datum_shift(&self.source, &self.target, lat, lon)?;
+
+        self.target.projection.forward(lat, lon, false)
+    }
+
+    pub fn inverse(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let (lat, lon) = self.target.projection.inverse(x, y, false)?;
+        let (lat, lon) = datum_shift(&self.target, &self.source, lat, lon)?;
+
+        self.source.projection.forward(lat, lon, false)
+    }
+}
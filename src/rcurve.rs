@@ -22,6 +22,10 @@ pub fn geocentric_radius(geodetic_lat: f64, ell: &Ellipsoid, deg: bool) -> Optio
     Some((n / d).sqrt())
 }
 
+pub(crate) fn parallel_radius(lat: f64, ell: &Ellipsoid) -> f64 {
+    lat.cos() / (1. - (ell.eccentricity * lat.sin()).powi(2)).sqrt()
+}
+
 pub fn parallel(lat: f64, ell: &Ellipsoid, deg: bool) -> Option<f64> {
     let lat = if deg { lat.to_radians() } else { lat };
 
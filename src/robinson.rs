@@ -0,0 +1,134 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::tmerc::wrap_lon;
+use crate::Ellipsoid;
+
+const PLEN: [f64; 19] = [
+    1.0000, 0.9986, 0.9954, 0.9900, 0.9822, 0.9730, 0.9600, 0.9427, 0.9216, 0.8962, 0.8679, 0.8350,
+    0.7986, 0.7597, 0.7186, 0.6732, 0.6213, 0.5722, 0.5322,
+];
+
+const PDFE: [f64; 19] = [
+    0.0000, 0.0620, 0.1240, 0.1860, 0.2480, 0.3100, 0.3720, 0.4340, 0.4958, 0.5571, 0.6176, 0.6769,
+    0.7346, 0.7903, 0.8435, 0.8936, 0.9394, 0.9761, 1.0000,
+];
+
+const FXC: f64 = 0.8487;
+const FYC: f64 = 1.3523;
+
+fn interpolate(table: &[f64; 19], lat: f64, odd: bool) -> f64 {
+    let node = |i: isize| -> f64 {
+        if i < 0 {
+            if odd {
+                -table[(-i) as usize]
+            } else {
+                table[(-i) as usize]
+            }
+        } else if i > 18 {
+            2. * table[18] - table[36 - i as usize]
+        } else {
+            table[i as usize]
+        }
+    };
+
+    let u = lat.abs().to_degrees() / 5.;
+    let i = (u.floor() as isize).min(17);
+    let t = u - i as f64;
+
+    let p0 = node(i - 1);
+    let p1 = node(i);
+    let p2 = node(i + 1);
+    let p3 = node(i + 2);
+
+    let m1 = (p2 - p0) / 2.;
+    let m2 = (p3 - p1) / 2.;
+
+    let t2 = t.powi(2);
+    let t3 = t.powi(3);
+
+    (2. * t3 - 3. * t2 + 1.) * p1
+        + (t3 - 2. * t2 + t) * m1
+        + (-2. * t3 + 3. * t2) * p2
+        + (t3 - t2) * m2
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Robinson {
+    pub lon0: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+    pub ellipsoid: Ellipsoid,
+}
+
+impl Robinson {
+    pub fn new(
+        lon0: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let lon0 = if deg { lon0.to_radians() } else { lon0 };
+
+        Some(Robinson {
+            lon0,
+            false_easting,
+            false_northing,
+            ellipsoid: ell.clone(),
+        })
+    }
+
+    pub fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        if lat.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        let r = self.ellipsoid.semimajor_axis;
+
+        let x = FXC * r * interpolate(&PLEN, lat, false) * wrap_lon(lon - self.lon0);
+        let y = FYC * r * interpolate(&PDFE, lat, true) * lat.signum();
+
+        Some((self.false_easting + x, self.false_northing + y))
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)> {
+        let r = self.ellipsoid.semimajor_axis;
+
+        let x = (x - self.false_easting) / (FXC * r);
+        let y = (y - self.false_northing) / (FYC * r);
+
+        if y.abs() > 1. + 1e-12 {
+            return None;
+        }
+
+        let mut lo = 0.;
+        let mut hi = FRAC_PI_2;
+
+        for _ in 0..64 {
+            let mid = (lo + hi) / 2.;
+
+            if interpolate(&PDFE, mid, true) < y.abs() {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+
+        let lat = (lo + hi) / 2. * y.signum();
+        let dlon = x / interpolate(&PLEN, lat, false);
+
+        if dlon.abs() > std::f64::consts::PI + 1e-12 {
+            return None;
+        }
+
+        let lon = wrap_lon(self.lon0 + dlon);
+
+        let lat = if deg { lat.to_degrees() } else { lat };
+        let lon = if deg { lon.to_degrees() } else { lon };
+
+        Some((lat, lon))
+    }
+}
@@ -0,0 +1,587 @@
+use std::collections::{BTreeSet, VecDeque};
+use std::f64::consts::PI;
+use std::fmt;
+
+use crate::{cart2sph, sph2cart, vdist, Ellipsoid};
+
+pub const S2_MAX_LEVEL: u8 = 30;
+
+const MAX_SIZE: i64 = 1 << S2_MAX_LEVEL;
+const POS_BITS: u32 = 2 * S2_MAX_LEVEL as u32 + 1;
+const MAX_CELLS: usize = 1 << 20;
+
+const SWAP: u8 = 1;
+const INVERT: u8 = 2;
+
+const POS_TO_IJ: [[u8; 4]; 4] = [[0, 1, 3, 2], [0, 2, 3, 1], [3, 2, 0, 1], [3, 1, 0, 2]];
+const IJ_TO_POS: [[u8; 4]; 4] = [[0, 1, 3, 2], [0, 3, 1, 2], [2, 3, 1, 0], [2, 1, 3, 0]];
+const POS_TO_ORIENTATION: [u8; 4] = [SWAP, 0, 0, INVERT | SWAP];
+
+type Vector = (f64, f64, f64);
+
+fn dot(a: Vector, b: Vector) -> f64 {
+    a.0 * b.0 + a.1 * b.1 + a.2 * b.2
+}
+
+fn cross(a: Vector, b: Vector) -> Vector {
+    (
+        a.1 * b.2 - a.2 * b.1,
+        a.2 * b.0 - a.0 * b.2,
+        a.0 * b.1 - a.1 * b.0,
+    )
+}
+
+fn normalize(a: Vector) -> Vector {
+    let norm = dot(a, a).sqrt();
+
+    (a.0 / norm, a.1 / norm, a.2 / norm)
+}
+
+fn face_uv_to_xyz(face: u8, u: f64, v: f64) -> Vector {
+    match face {
+        0 => (1., u, v),
+        1 => (-u, 1., v),
+        2 => (-u, -v, 1.),
+        3 => (-1., -v, -u),
+        4 => (v, -1., -u),
+        _ => (v, u, -1.),
+    }
+}
+
+fn xyz_to_face_uv(p: Vector) -> (u8, f64, f64) {
+    let (ax, ay, az) = (p.0.abs(), p.1.abs(), p.2.abs());
+
+    let axis = if ax > ay {
+        if ax > az {
+            0
+        } else {
+            2
+        }
+    } else if ay > az {
+        1
+    } else {
+        2
+    };
+
+    let negative = [p.0, p.1, p.2][axis] < 0.;
+    let face = axis as u8 + if negative { 3 } else { 0 };
+
+    let (u, v) = match face {
+        0 => (p.1 / p.0, p.2 / p.0),
+        1 => (-p.0 / p.1, p.2 / p.1),
+        2 => (-p.0 / p.2, -p.1 / p.2),
+        3 => (p.2 / p.0, p.1 / p.0),
+        4 => (p.2 / p.1, -p.0 / p.1),
+        _ => (-p.1 / p.2, -p.0 / p.2),
+    };
+
+    (face, u, v)
+}
+
+fn st_to_uv(s: f64) -> f64 {
+    if s >= 0.5 {
+        (4. * s * s - 1.) / 3.
+    } else {
+        (1. - 4. * (1. - s) * (1. - s)) / 3.
+    }
+}
+
+fn uv_to_st(u: f64) -> f64 {
+    if u >= 0. {
+        0.5 * (1. + 3. * u).sqrt()
+    } else {
+        1. - 0.5 * (1. - 3. * u).sqrt()
+    }
+}
+
+fn st_to_ij(s: f64) -> i64 {
+    ((s * MAX_SIZE as f64).floor() as i64).clamp(0, MAX_SIZE - 1)
+}
+
+fn xyz2geodetic(p: Vector) -> (f64, f64) {
+    let (lon, lat, _) = cart2sph(p.0, p.1, p.2);
+
+    (lat, lon)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct S2CellId(u64);
+
+impl S2CellId {
+    pub fn from_face(face: u8) -> Option<Self> {
+        if face > 5 {
+            return None;
+        }
+
+        Some(S2CellId(((face as u64) << POS_BITS) | 1 << (POS_BITS - 1)))
+    }
+
+    pub fn from_id(id: u64) -> Option<Self> {
+        let cell = S2CellId(id);
+
+        if cell.is_valid() {
+            Some(cell)
+        } else {
+            None
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.0
+    }
+
+    fn from_face_ij(face: u8, i: i64, j: i64) -> Self {
+        let mut orientation = face & SWAP;
+        let mut id = face as u64;
+
+        for k in (0..S2_MAX_LEVEL as i64).rev() {
+            let ij = (((i >> k) & 1) << 1 | ((j >> k) & 1)) as usize;
+            let pos = IJ_TO_POS[orientation as usize][ij];
+
+            id = id << 2 | pos as u64;
+            orientation ^= POS_TO_ORIENTATION[pos as usize];
+        }
+
+        S2CellId(id << 1 | 1)
+    }
+
+    fn from_face_ij_wrap(face: u8, i: i64, j: i64) -> Self {
+        let limit = 1. + f64::EPSILON;
+        let scale = 1. / MAX_SIZE as f64;
+
+        let u = (scale * (2 * (i - MAX_SIZE / 2) + 1) as f64).clamp(-limit, limit);
+        let v = (scale * (2 * (j - MAX_SIZE / 2) + 1) as f64).clamp(-limit, limit);
+
+        let (face, u, v) = xyz_to_face_uv(face_uv_to_xyz(face, u, v));
+
+        Self::from_face_ij(face, st_to_ij(0.5 * (u + 1.)), st_to_ij(0.5 * (v + 1.)))
+    }
+
+    fn from_face_ij_same(face: u8, i: i64, j: i64) -> Self {
+        if (0..MAX_SIZE).contains(&i) && (0..MAX_SIZE).contains(&j) {
+            Self::from_face_ij(face, i, j)
+        } else {
+            Self::from_face_ij_wrap(face, i, j)
+        }
+    }
+
+    fn from_point(p: Vector) -> Self {
+        let (face, u, v) = xyz_to_face_uv(p);
+
+        Self::from_face_ij(face, st_to_ij(uv_to_st(u)), st_to_ij(uv_to_st(v)))
+    }
+
+    pub fn from_token(token: &str) -> Option<Self> {
+        let token = token.trim();
+
+        if token.is_empty() || token.len() > 16 || !token.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let id = u64::from_str_radix(token, 16).ok()? << (4 * (16 - token.len()));
+
+        S2CellId::from_id(id)
+    }
+
+    pub fn to_token(&self) -> String {
+        if self.0 == 0 {
+            return "X".to_string();
+        }
+
+        format!("{:016x}", self.0).trim_end_matches('0').to_string()
+    }
+
+    fn lsb(&self) -> u64 {
+        self.0 & self.0.wrapping_neg()
+    }
+
+    fn lsb_for_level(level: u8) -> u64 {
+        1 << (2 * (S2_MAX_LEVEL - level) as u64)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.face() < 6 && self.lsb() & 0x1555_5555_5555_5555 != 0
+    }
+
+    pub fn face(&self) -> u8 {
+        (self.0 >> POS_BITS) as u8
+    }
+
+    pub fn level(&self) -> u8 {
+        S2_MAX_LEVEL - (self.0.trailing_zeros() / 2) as u8
+    }
+
+    pub fn is_leaf(&self) -> bool {
+        self.0 & 1 == 1
+    }
+
+    pub fn range_min(&self) -> Self {
+        S2CellId(self.0 - (self.lsb() - 1))
+    }
+
+    pub fn range_max(&self) -> Self {
+        S2CellId(self.0 + (self.lsb() - 1))
+    }
+
+    pub fn contains(&self, other: &S2CellId) -> bool {
+        self.range_min() <= *other && *other <= self.range_max()
+    }
+
+    pub fn parent(&self, level: u8) -> Option<Self> {
+        if level > self.level() {
+            return None;
+        }
+
+        let lsb = Self::lsb_for_level(level);
+
+        Some(S2CellId((self.0 & lsb.wrapping_neg()) | lsb))
+    }
+
+    pub fn children(&self) -> Option<[Self; 4]> {
+        if self.is_leaf() {
+            return None;
+        }
+
+        let lsb = self.lsb();
+        let child = lsb >> 2;
+        let first = self.0 - lsb + child;
+
+        Some([
+            S2CellId(first),
+            S2CellId(first + 2 * child),
+            S2CellId(first + 4 * child),
+            S2CellId(first + 6 * child),
+        ])
+    }
+
+    fn face_ij(&self) -> (u8, i64, i64) {
+        let face = self.face();
+        let mut orientation = face & SWAP;
+        let (mut i, mut j) = (0, 0);
+
+        for k in 0..S2_MAX_LEVEL as u32 {
+            let pos = ((self.0 >> (POS_BITS - 2 - 2 * k)) & 3) as usize;
+            let ij = POS_TO_IJ[orientation as usize][pos] as i64;
+
+            i = i << 1 | ij >> 1;
+            j = j << 1 | ij & 1;
+            orientation ^= POS_TO_ORIENTATION[pos];
+        }
+
+        let size = self.size();
+
+        (face, i & !(size - 1), j & !(size - 1))
+    }
+
+    fn size(&self) -> i64 {
+        1 << (S2_MAX_LEVEL - self.level())
+    }
+
+    fn point(&self, i: f64, j: f64) -> Vector {
+        let u = st_to_uv(i / MAX_SIZE as f64);
+        let v = st_to_uv(j / MAX_SIZE as f64);
+
+        normalize(face_uv_to_xyz(self.face(), u, v))
+    }
+
+    fn centre_point(&self) -> Vector {
+        let (_, i, j) = self.face_ij();
+        let half = self.size() as f64 / 2.;
+
+        self.point(i as f64 + half, j as f64 + half)
+    }
+
+    fn vertex_points(&self) -> [Vector; 4] {
+        let (_, i, j) = self.face_ij();
+        let (i0, j0) = (i as f64, j as f64);
+        let (i1, j1) = (i0 + self.size() as f64, j0 + self.size() as f64);
+
+        [
+            self.point(i0, j0),
+            self.point(i1, j0),
+            self.point(i1, j1),
+            self.point(i0, j1),
+        ]
+    }
+
+    pub fn centre(&self, deg: bool) -> (f64, f64) {
+        let (lat, lon) = xyz2geodetic(self.centre_point());
+
+        if deg {
+            (lat.to_degrees(), lon.to_degrees())
+        } else {
+            (lat, lon)
+        }
+    }
+
+    pub fn vertices(&self, deg: bool) -> [(f64, f64); 4] {
+        self.vertex_points().map(|p| {
+            let (lat, lon) = xyz2geodetic(p);
+
+            if deg {
+                (lat.to_degrees(), lon.to_degrees())
+            } else {
+                (lat, lon)
+            }
+        })
+    }
+
+    pub fn edge_neighbours(&self) -> [Self; 4] {
+        let level = self.level();
+        let size = self.size();
+        let (face, i, j) = self.face_ij();
+
+        [
+            Self::from_face_ij_same(face, i, j - size),
+            Self::from_face_ij_same(face, i + size, j),
+            Self::from_face_ij_same(face, i, j + size),
+            Self::from_face_ij_same(face, i - size, j),
+        ]
+        .map(|cell| cell.parent(level).unwrap())
+    }
+
+    pub fn neighbours(&self) -> Vec<Self> {
+        let level = self.level();
+        let size = self.size();
+        let (face, i, j) = self.face_ij();
+
+        let mut cells = BTreeSet::new();
+
+        for di in -1..=1 {
+            for dj in -1..=1 {
+                let cell = Self::from_face_ij_same(face, i + di * size, j + dj * size);
+                cells.insert(cell.parent(level).unwrap());
+            }
+        }
+
+        cells.remove(self);
+        cells.into_iter().collect()
+    }
+}
+
+impl fmt::Display for S2CellId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_token())
+    }
+}
+
+fn to_point(lat: f64, lon: f64) -> Vector {
+    sph2cart(lon, lat, 1.)
+}
+
+pub fn geodetic2s2(lat: f64, lon: f64, level: u8, deg: bool) -> Option<S2CellId> {
+    let lat = if deg { lat.to_radians() } else { lat };
+    let lon = if deg { lon.to_radians() } else { lon };
+
+    if lat.abs() > std::f64::consts::FRAC_PI_2 || level > S2_MAX_LEVEL {
+        return None;
+    }
+
+    S2CellId::from_point(to_point(lat, lon)).parent(level)
+}
+
+fn cover<F: Fn(&S2CellId) -> bool>(start: &[S2CellId], intersects: F) -> Option<Vec<S2CellId>> {
+    let mut queue: VecDeque<S2CellId> = start.iter().copied().collect();
+    let mut seen: BTreeSet<S2CellId> = start.iter().copied().collect();
+    let mut cells = BTreeSet::new();
+
+    while let Some(cell) = queue.pop_front() {
+        if !intersects(&cell) {
+            continue;
+        }
+
+        cells.insert(cell);
+
+        if cells.len() > MAX_CELLS {
+            return None;
+        }
+
+        for neighbour in cell.neighbours() {
+            if seen.insert(neighbour) {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    Some(cells.into_iter().collect())
+}
+
+fn distance(a: Vector, b: Vector, ell: &Ellipsoid) -> f64 {
+    let (lat1, lon1) = xyz2geodetic(a);
+    let (lat2, lon2) = xyz2geodetic(b);
+
+    vdist(lat1, lon1, lat2, lon2, ell, false).map_or(f64::INFINITY, |(s, _)| s)
+}
+
+fn edge_distance(p: Vector, a: Vector, b: Vector, ell: &Ellipsoid) -> f64 {
+    let along = |t: f64| {
+        normalize((
+            a.0 + t * (b.0 - a.0),
+            a.1 + t * (b.1 - a.1),
+            a.2 + t * (b.2 - a.2),
+        ))
+    };
+
+    let (mut lo, mut hi) = (0., 1.);
+
+    for _ in 0..60 {
+        let m1 = lo + (hi - lo) / 3.;
+        let m2 = hi - (hi - lo) / 3.;
+
+        if distance(p, along(m1), ell) < distance(p, along(m2), ell) {
+            hi = m2;
+        } else {
+            lo = m1;
+        }
+    }
+
+    distance(p, along((lo + hi) / 2.), ell)
+}
+
+pub fn s2_circle(
+    lat: f64,
+    lon: f64,
+    radius: f64,
+    level: u8,
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<Vec<S2CellId>> {
+    let lat = if deg { lat.to_radians() } else { lat };
+    let lon = if deg { lon.to_radians() } else { lon };
+
+    if radius < 0. {
+        return None;
+    }
+
+    let a = ell.semimajor_axis;
+    let cap = 2. * PI * a.powi(2) * (1. - (radius / a).min(PI).cos());
+    let cell = 4. * PI * a.powi(2) / (6. * 4f64.powi(level as i32));
+
+    if cap / cell > MAX_CELLS as f64 {
+        return None;
+    }
+
+    let start = geodetic2s2(lat, lon, level, false)?;
+    let p = to_point(lat, lon);
+
+    cover(&[start], |cell| {
+        if *cell == start {
+            return true;
+        }
+
+        let centre = cell.centre_point();
+        let vertices = cell.vertex_points();
+
+        let d = distance(p, centre, ell);
+        let half_diagonal = vertices
+            .iter()
+            .map(|&v| distance(centre, v, ell))
+            .fold(0., f64::max);
+
+        d <= radius
+            || (d <= radius + half_diagonal
+                && (0..4)
+                    .any(|k| edge_distance(p, vertices[k], vertices[(k + 1) % 4], ell) <= radius))
+    })
+}
+
+fn crosses(a: Vector, b: Vector, c: Vector, d: Vector) -> bool {
+    let ab = cross(a, b);
+    let acb = -dot(ab, c);
+    let bda = dot(ab, d);
+
+    if acb * bda <= 0. {
+        return false;
+    }
+
+    let cd = cross(c, d);
+    let cbd = -dot(cd, b);
+    let dac = dot(cd, a);
+
+    acb * cbd > 0. && acb * dac > 0.
+}
+
+fn inside(p: Vector, outside: Vector, polygon: &[Vector]) -> bool {
+    let mid = (p.0 + outside.0, p.1 + outside.1, p.2 + outside.2);
+
+    let mid = if dot(mid, mid) > 1e-12 {
+        normalize(mid)
+    } else {
+        let axis = if p.0.abs() < 0.9 {
+            (1., 0., 0.)
+        } else {
+            (0., 1., 0.)
+        };
+        normalize(cross(p, axis))
+    };
+
+    let mut inside = false;
+
+    for k in 0..polygon.len() {
+        let (a, b) = (polygon[k], polygon[(k + 1) % polygon.len()]);
+
+        if crosses(p, mid, a, b) != crosses(mid, outside, a, b) {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+pub fn s2_polygon(polygon: &[(f64, f64)], level: u8, deg: bool) -> Option<Vec<S2CellId>> {
+    if polygon.len() < 3 {
+        return None;
+    }
+
+    let mut points = Vec::with_capacity(polygon.len());
+    let mut start = Vec::with_capacity(polygon.len());
+
+    for &(lat, lon) in polygon {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        start.push(geodetic2s2(lat, lon, level, false)?);
+        points.push(to_point(lat, lon));
+    }
+
+    let sum = points
+        .iter()
+        .fold((0., 0., 0.), |s, p| (s.0 + p.0, s.1 + p.1, s.2 + p.2));
+
+    if dot(sum, sum) < 1e-24 {
+        return None;
+    }
+
+    let centroid = normalize(sum);
+
+    if points.iter().any(|&p| dot(p, centroid) <= 0.) {
+        return None;
+    }
+
+    let outside = normalize((-centroid.0 + 1e-7, -centroid.1 + 2e-7, -centroid.2 + 3e-7));
+
+    cover(&start, |cell| {
+        if start.contains(cell) {
+            return true;
+        }
+
+        let vertices = cell.vertex_points();
+
+        if inside(cell.centre_point(), outside, &points)
+            || vertices.iter().any(|&v| inside(v, outside, &points))
+        {
+            return true;
+        }
+
+        (0..4).any(|k| {
+            (0..points.len()).any(|m| {
+                crosses(
+                    vertices[k],
+                    vertices[(k + 1) % 4],
+                    points[m],
+                    points[(m + 1) % points.len()],
+                )
+            })
+        })
+    })
+}
@@ -0,0 +1,68 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::lox::meridian_arc;
+use crate::rcurve::parallel_radius;
+use crate::tmerc::wrap_lon;
+use crate::{rectifying2geodetic, rsphere, Ellipsoid};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sinusoidal {
+    pub lon0: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+    pub ellipsoid: Ellipsoid,
+}
+
+impl Sinusoidal {
+    pub fn new(
+        lon0: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let lon0 = if deg { lon0.to_radians() } else { lon0 };
+
+        Some(Sinusoidal {
+            lon0,
+            false_easting,
+            false_northing,
+            ellipsoid: ell.clone(),
+        })
+    }
+
+    pub fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        let ell = &self.ellipsoid;
+        let x = ell.semimajor_axis * parallel_radius(lat, ell) * wrap_lon(lon - self.lon0);
+        let y = meridian_arc(0., lat, ell, false)?;
+
+        Some((self.false_easting + x, self.false_northing + y))
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)> {
+        let ell = &self.ellipsoid;
+        let mu = (y - self.false_northing) / rsphere::rectifying(ell);
+
+        let lat = rectifying2geodetic(mu, ell, false)?;
+
+        let lon = if FRAC_PI_2 - lat.abs() < 1e-12 {
+            self.lon0
+        } else {
+            let dlon = (x - self.false_easting) / (ell.semimajor_axis * parallel_radius(lat, ell));
+
+            if dlon.abs() > PI + 1e-12 {
+                return None;
+            }
+
+            wrap_lon(self.lon0 + dlon)
+        };
+
+        let lat = if deg { lat.to_degrees() } else { lat };
+        let lon = if deg { lon.to_degrees() } else { lon };
+
+        Some((lat, lon))
+    }
+}
@@ -0,0 +1,145 @@
+use std::f64::consts::PI;
+
+pub const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+pub const MAX_ZOOM: u8 = 30;
+
+fn world_fraction(lat: f64, lon: f64) -> (f64, f64) {
+    let lat = lat.clamp(-MAX_LATITUDE, MAX_LATITUDE).to_radians();
+
+    let x = (lon + 180.) / 360.;
+    let y = (1. - (lat.tan().asinh() / PI)) / 2.;
+
+    (x, y)
+}
+
+fn world_geodetic(x: f64, y: f64) -> (f64, f64) {
+    let lon = x * 360. - 180.;
+    let lat = (PI * (1. - 2. * y)).sinh().atan().to_degrees();
+
+    (lat, lon)
+}
+
+pub fn geodetic2pixel(
+    lat: f64,
+    lon: f64,
+    zoom: u8,
+    tile_size: u32,
+    deg: bool,
+) -> Option<(f64, f64)> {
+    let lat = if deg { lat } else { lat.to_degrees() };
+    let lon = if deg { lon } else { lon.to_degrees() };
+
+    if zoom > MAX_ZOOM || lat.abs() > 90. || !lon.is_finite() {
+        return None;
+    }
+
+    let lon = if (-180. ..=180.).contains(&lon) {
+        lon
+    } else {
+        (lon + 180.).rem_euclid(360.) - 180.
+    };
+
+    let size = tile_size as f64 * (1u64 << zoom) as f64;
+    let (x, y) = world_fraction(lat, lon);
+
+    Some((x * size, y * size))
+}
+
+pub fn pixel2geodetic(x: f64, y: f64, zoom: u8, tile_size: u32, deg: bool) -> Option<(f64, f64)> {
+    if zoom > MAX_ZOOM {
+        return None;
+    }
+
+    let size = tile_size as f64 * (1u64 << zoom) as f64;
+
+    if !(0. ..=size).contains(&x) || !(0. ..=size).contains(&y) {
+        return None;
+    }
+
+    let (lat, lon) = world_geodetic(x / size, y / size);
+
+    if deg {
+        Some((lat, lon))
+    } else {
+        Some((lat.to_radians(), lon.to_radians()))
+    }
+}
+
+pub fn geodetic2tile(lat: f64, lon: f64, zoom: u8, deg: bool) -> Option<(u32, u32)> {
+    let (x, y) = geodetic2pixel(lat, lon, zoom, 1, deg)?;
+    let max = ((1u64 << zoom) - 1) as f64;
+
+    Some((x.floor().min(max) as u32, y.floor().min(max) as u32))
+}
+
+pub fn tile2geodetic(x: u32, y: u32, zoom: u8, deg: bool) -> Option<(f64, f64)> {
+    if zoom > MAX_ZOOM || x as u64 > 1u64 << zoom || y as u64 > 1u64 << zoom {
+        return None;
+    }
+
+    pixel2geodetic(x as f64, y as f64, zoom, 1, deg)
+}
+
+pub fn tile_bbox(x: u32, y: u32, zoom: u8, deg: bool) -> Option<(f64, f64, f64, f64)> {
+    if zoom > MAX_ZOOM || x as u64 >= 1u64 << zoom || y as u64 >= 1u64 << zoom {
+        return None;
+    }
+
+    let (north, west) = tile2geodetic(x, y, zoom, deg)?;
+    let (south, east) = tile2geodetic(x + 1, y + 1, zoom, deg)?;
+
+    Some((south, west, north, east))
+}
+
+pub fn tile2quadkey(x: u32, y: u32, zoom: u8) -> Option<String> {
+    if zoom > MAX_ZOOM || x as u64 >= 1u64 << zoom || y as u64 >= 1u64 << zoom {
+        return None;
+    }
+
+    let quadkey = (1..=zoom)
+        .rev()
+        .map(|i| {
+            let mask = 1 << (i - 1);
+            let mut digit = b'0';
+
+            if x & mask != 0 {
+                digit += 1;
+            }
+
+            if y & mask != 0 {
+                digit += 2;
+            }
+
+            digit as char
+        })
+        .collect();
+
+    Some(quadkey)
+}
+
+pub fn quadkey2tile(quadkey: &str) -> Option<(u32, u32, u8)> {
+    if quadkey.len() > MAX_ZOOM as usize {
+        return None;
+    }
+
+    let mut x = 0;
+    let mut y = 0;
+
+    for c in quadkey.chars() {
+        x <<= 1;
+        y <<= 1;
+
+        match c {
+            '0' => {}
+            '1' => x |= 1,
+            '2' => y |= 1,
+            '3' => {
+                x |= 1;
+                y |= 1;
+            }
+            _ => return None,
+        }
+    }
+
+    Some((x, y, quadkey.len() as u8))
+}
@@ -0,0 +1,81 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::{meridian, parallel, Projection};
+
+const STEP: f64 = 1e-5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tissot {
+    pub meridian_scale: f64,
+    pub parallel_scale: f64,
+    pub areal_scale: f64,
+    pub angular_distortion: f64,
+    pub semimajor: f64,
+    pub semiminor: f64,
+    pub azimuth: f64,
+    pub intersection: f64,
+}
+
+pub fn tissot<P: Projection + ?Sized>(
+    projection: &P,
+    lat: f64,
+    lon: f64,
+    deg: bool,
+) -> Option<Tissot> {
+    let lat = if deg { lat.to_radians() } else { lat };
+    let lon = if deg { lon.to_radians() } else { lon };
+
+    if FRAC_PI_2 - lat.abs() < STEP {
+        return None;
+    }
+
+    let ell = projection.ellipsoid();
+    let m = meridian(lat, ell, false)?;
+    let p = parallel(lat, ell, false)?;
+
+    let (xs, ys) = projection.forward(lat - STEP, lon, false)?;
+    let (xn, yn) = projection.forward(lat + STEP, lon, false)?;
+    let (xw, yw) = projection.forward(lat, lon - STEP, false)?;
+    let (xe, ye) = projection.forward(lat, lon + STEP, false)?;
+
+    let (x_lat, y_lat) = ((xn - xs) / (2. * STEP * m), (yn - ys) / (2. * STEP * m));
+    let (x_lon, y_lon) = ((xe - xw) / (2. * STEP * p), (ye - yw) / (2. * STEP * p));
+
+    let h = x_lat.hypot(y_lat);
+    let k = x_lon.hypot(y_lon);
+    let s = (y_lat * x_lon - x_lat * y_lon).abs();
+
+    let u = (x_lon + y_lat).hypot(y_lon - x_lat);
+    let v = (x_lon - y_lat).hypot(y_lon + x_lat);
+
+    let (a1, b1) = (u.max(v), u.min(v));
+
+    let a = (a1 + b1) / 2.;
+    let b = (a1 - b1) / 2.;
+
+    let omega = 2. * ((a - b) / (a + b)).asin();
+    let theta = (s / (h * k)).clamp(-1., 1.).asin();
+
+    let e = k.powi(2);
+    let g = h.powi(2);
+    let f = x_lon * x_lat + y_lon * y_lat;
+
+    let azimuth = (FRAC_PI_2 - 0.5 * (2. * f).atan2(e - g)).rem_euclid(PI);
+
+    let (omega, azimuth, theta) = if deg {
+        (omega.to_degrees(), azimuth.to_degrees(), theta.to_degrees())
+    } else {
+        (omega, azimuth, theta)
+    };
+
+    Some(Tissot {
+        meridian_scale: h,
+        parallel_scale: k,
+        areal_scale: s,
+        angular_distortion: omega,
+        semimajor: a,
+        semiminor: b,
+        azimuth,
+        intersection: theta,
+    })
+}
@@ -0,0 +1,205 @@
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+use crate::Ellipsoid;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransverseMercator {
+    pub lat0: f64,
+    pub lon0: f64,
+    pub k0: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+    pub ellipsoid: Ellipsoid,
+    radius: f64,
+    alpha: [f64; 6],
+    beta: [f64; 6],
+    origin: f64,
+}
+
+pub(crate) fn conformal_tan(tau: f64, e: f64) -> f64 {
+    let sigma = (e * (e * tau / tau.hypot(1.)).atanh()).sinh();
+
+    tau * sigma.hypot(1.) - sigma * tau.hypot(1.)
+}
+
+pub(crate) fn geodetic_tan(taup: f64, e: f64) -> f64 {
+    let e2 = e.powi(2);
+    let mut tau = taup;
+
+    for _ in 0..10 {
+        let taupi = conformal_tan(tau, e);
+        let dtau = (taup - taupi) / taupi.hypot(1.) * (1. + (1. - e2) * tau.powi(2))
+            / ((1. - e2) * tau.hypot(1.));
+        tau += dtau;
+
+        if dtau.abs() <= 1e-14 * tau.abs().max(1.) {
+            break;
+        }
+    }
+
+    tau
+}
+
+pub(crate) fn wrap_lon(lon: f64) -> f64 {
+    (lon + PI).rem_euclid(TAU) - PI
+}
+
+impl TransverseMercator {
+    pub fn new(
+        lat0: f64,
+        lon0: f64,
+        k0: f64,
+        false_easting: f64,
+        false_northing: f64,
+        ell: &Ellipsoid,
+        deg: bool,
+    ) -> Option<Self> {
+        let lat0 = if deg { lat0.to_radians() } else { lat0 };
+        let lon0 = if deg { lon0.to_radians() } else { lon0 };
+
+        if lat0.abs() > FRAC_PI_2 || k0 <= 0. {
+            return None;
+        }
+
+        let n = ell.thirdflattening;
+        let n2 = n.powi(2);
+        let n3 = n.powi(3);
+        let n4 = n.powi(4);
+        let n5 = n.powi(5);
+        let n6 = n.powi(6);
+
+        let radius = ell.semimajor_axis / (1. + n) * (1. + n2 / 4. + n4 / 64. + n6 / 256.);
+
+        let alpha = [
+            n / 2. - 2. * n2 / 3. + 5. * n3 / 16. + 41. * n4 / 180. - 127. * n5 / 288.
+                + 7891. * n6 / 37800.,
+            13. * n2 / 48. - 3. * n3 / 5. + 557. * n4 / 1440. + 281. * n5 / 630.
+                - 1983433. * n6 / 1935360.,
+            61. * n3 / 240. - 103. * n4 / 140. + 15061. * n5 / 26880. + 167603. * n6 / 181440.,
+            49561. * n4 / 161280. - 179. * n5 / 168. + 6601661. * n6 / 7257600.,
+            34729. * n5 / 80640. - 3418889. * n6 / 1995840.,
+            212378941. * n6 / 319334400.,
+        ];
+
+        let beta = [
+            n / 2. - 2. * n2 / 3. + 37. * n3 / 96. - n4 / 360. - 81. * n5 / 512.
+                + 96199. * n6 / 604800.,
+            n2 / 48. + n3 / 15. - 437. * n4 / 1440. + 46. * n5 / 105. - 1118711. * n6 / 3870720.,
+            17. * n3 / 480. - 37. * n4 / 840. - 209. * n5 / 4480. + 5569. * n6 / 90720.,
+            4397. * n4 / 161280. - 11. * n5 / 504. - 830251. * n6 / 7257600.,
+            4583. * n5 / 161280. - 108847. * n6 / 3991680.,
+            20648693. * n6 / 638668800.,
+        ];
+
+        let mut tmerc = TransverseMercator {
+            lat0,
+            lon0,
+            k0,
+            false_easting,
+            false_northing,
+            ellipsoid: ell.clone(),
+            radius,
+            alpha,
+            beta,
+            origin: 0.,
+        };
+
+        tmerc.origin = tmerc.gauss_kruger(lat0, 0.)?.1;
+
+        Some(tmerc)
+    }
+
+    fn gauss_kruger(&self, lat: f64, dlon: f64) -> Option<(f64, f64, f64, f64)> {
+        if lat.abs() > FRAC_PI_2 || dlon.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        let e = self.ellipsoid.eccentricity;
+        let tau = lat.tan();
+        let taup = conformal_tan(tau, e);
+
+        let xip = taup.atan2(dlon.cos());
+        let etap = (dlon.sin() / taup.hypot(dlon.cos())).asinh();
+
+        let mut xi = xip;
+        let mut eta = etap;
+        let mut p = 1.;
+        let mut q = 0.;
+
+        for (j, alpha) in self.alpha.iter().enumerate() {
+            let k = 2. * (j + 1) as f64;
+
+            xi += alpha * (k * xip).sin() * (k * etap).cosh();
+            eta += alpha * (k * xip).cos() * (k * etap).sinh();
+            p += k * alpha * (k * xip).cos() * (k * etap).cosh();
+            q += k * alpha * (k * xip).sin() * (k * etap).sinh();
+        }
+
+        let x = self.k0 * self.radius * eta;
+        let y = self.k0 * self.radius * xi;
+
+        let gamma = (taup / taup.hypot(1.) * dlon.tan()).atan() + q.atan2(p);
+        let kp = (1. - (e * lat.sin()).powi(2)).sqrt() * tau.hypot(1.) / taup.hypot(dlon.cos());
+        let kpp = self.radius / self.ellipsoid.semimajor_axis * p.hypot(q);
+
+        Some((x, y, gamma, self.k0 * kp * kpp))
+    }
+
+    pub fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        let (x, y, ..) = self.gauss_kruger(lat, wrap_lon(lon - self.lon0))?;
+
+        Some((
+            x + self.false_easting,
+            y - self.origin + self.false_northing,
+        ))
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)> {
+        let xi = (y - self.false_northing + self.origin) / (self.k0 * self.radius);
+        let eta = (x - self.false_easting) / (self.k0 * self.radius);
+
+        let mut xip = xi;
+        let mut etap = eta;
+
+        for (j, beta) in self.beta.iter().enumerate() {
+            let k = 2. * (j + 1) as f64;
+
+            xip -= beta * (k * xi).sin() * (k * eta).cosh();
+            etap -= beta * (k * xi).cos() * (k * eta).sinh();
+        }
+
+        if xip.abs() > FRAC_PI_2 {
+            return None;
+        }
+
+        let taup = xip.sin() / etap.sinh().hypot(xip.cos());
+        let dlon = etap.sinh().atan2(xip.cos());
+
+        let lat = geodetic_tan(taup, self.ellipsoid.eccentricity).atan();
+        let lon = wrap_lon(self.lon0 + dlon);
+
+        let lat = if deg { lat.to_degrees() } else { lat };
+        let lon = if deg { lon.to_degrees() } else { lon };
+
+        Some((lat, lon))
+    }
+
+    pub fn convergence(&self, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        let gamma = self.gauss_kruger(lat, wrap_lon(lon - self.lon0))?.2;
+
+        Some(if deg { gamma.to_degrees() } else { gamma })
+    }
+
+    pub fn scale(&self, lat: f64, lon: f64, deg: bool) -> Option<f64> {
+        let lat = if deg { lat.to_radians() } else { lat };
+        let lon = if deg { lon.to_radians() } else { lon };
+
+        Some(self.gauss_kruger(lat, wrap_lon(lon - self.lon0))?.3)
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{Ellipsoid, PolarStereographic};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ups {
+    pub band: char,
+    pub easting: f64,
+    pub northing: f64,
+    pub convergence: f64,
+    pub scale: f64,
+}
+
+impl Ups {
+    pub fn northern(&self) -> bool {
+        self.band >= 'Y'
+    }
+}
+
+pub fn ups_projection(northern: bool, ell: &Ellipsoid) -> Option<PolarStereographic> {
+    PolarStereographic::variant_a(northern, 0., 0.994, 2_000_000., 2_000_000., ell, true)
+}
+
+pub fn geodetic2ups_hemisphere(
+    lat: f64,
+    lon: f64,
+    northern: bool,
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<Ups> {
+    let stereo = ups_projection(northern, ell)?;
+    let (easting, northing) = stereo.forward(lat, lon, deg)?;
+
+    let band = match (northern, easting < 2_000_000.) {
+        (true, true) => 'Y',
+        (true, false) => 'Z',
+        (false, true) => 'A',
+        (false, false) => 'B',
+    };
+
+    Some(Ups {
+        band,
+        easting,
+        northing,
+        convergence: stereo.convergence(lat, lon, deg)?,
+        scale: stereo.scale(lat, lon, deg)?,
+    })
+}
+
+pub fn geodetic2ups(lat: f64, lon: f64, ell: &Ellipsoid, deg: bool) -> Option<Ups> {
+    let lat_deg = if deg { lat } else { lat.to_degrees() };
+
+    if (-80. ..84.).contains(&lat_deg) || lat_deg.abs() > 90. {
+        return None;
+    }
+
+    geodetic2ups_hemisphere(lat, lon, lat_deg > 0., ell, deg)
+}
+
+pub fn ups2geodetic(
+    northern: bool,
+    easting: f64,
+    northing: f64,
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64)> {
+    ups_projection(northern, ell)?.inverse(easting, northing, deg)
+}
@@ -0,0 +1,107 @@
+use crate::{Ellipsoid, TransverseMercator};
+
+const BANDS: &[u8] = b"CDEFGHJKLMNPQRSTUVWX";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Utm {
+    pub zone: u8,
+    pub band: char,
+    pub easting: f64,
+    pub northing: f64,
+    pub convergence: f64,
+    pub scale: f64,
+}
+
+impl Utm {
+    pub fn northern(&self) -> bool {
+        self.band >= 'N'
+    }
+}
+
+pub fn utm_band(lat: f64, deg: bool) -> Option<char> {
+    let lat = if deg { lat } else { lat.to_degrees() };
+
+    if !(-80. ..=84.).contains(&lat) {
+        return None;
+    }
+
+    let index = (((lat + 80.) / 8.).floor() as usize).min(BANDS.len() - 1);
+
+    Some(BANDS[index] as char)
+}
+
+pub fn utm_zone(lat: f64, lon: f64, deg: bool) -> Option<(u8, char)> {
+    let lat = if deg { lat } else { lat.to_degrees() };
+    let lon = if deg { lon } else { lon.to_degrees() };
+
+    let band = utm_band(lat, true)?;
+    let lon = if (-180. ..=180.).contains(&lon) {
+        lon
+    } else {
+        (lon + 180.).rem_euclid(360.) - 180.
+    };
+
+    let mut zone = (((lon + 180.) / 6.).floor() as u8).min(59) + 1;
+
+    if band == 'V' && (3. ..12.).contains(&lon) {
+        zone = 32;
+    }
+
+    if band == 'X' && (0. ..42.).contains(&lon) {
+        zone = if lon < 9. {
+            31
+        } else if lon < 21. {
+            33
+        } else if lon < 33. {
+            35
+        } else {
+            37
+        };
+    }
+
+    Some((zone, band))
+}
+
+pub fn utm_projection(zone: u8, northern: bool, ell: &Ellipsoid) -> Option<TransverseMercator> {
+    if !(1..=60).contains(&zone) {
+        return None;
+    }
+
+    let lon0 = 6. * zone as f64 - 183.;
+    let false_northing = if northern { 0. } else { 10_000_000. };
+
+    TransverseMercator::new(0., lon0, 0.9996, 500_000., false_northing, ell, true)
+}
+
+pub fn geodetic2utm_zone(lat: f64, lon: f64, zone: u8, ell: &Ellipsoid, deg: bool) -> Option<Utm> {
+    let band = utm_band(lat, deg)?;
+    let tmerc = utm_projection(zone, band >= 'N', ell)?;
+
+    let (easting, northing) = tmerc.forward(lat, lon, deg)?;
+
+    Some(Utm {
+        zone,
+        band,
+        easting,
+        northing,
+        convergence: tmerc.convergence(lat, lon, deg)?,
+        scale: tmerc.scale(lat, lon, deg)?,
+    })
+}
+
+pub fn geodetic2utm(lat: f64, lon: f64, ell: &Ellipsoid, deg: bool) -> Option<Utm> {
+    let (zone, _) = utm_zone(lat, lon, deg)?;
+
+    geodetic2utm_zone(lat, lon, zone, ell, deg)
+}
+
+pub fn utm2geodetic(
+    zone: u8,
+    northern: bool,
+    easting: f64,
+    northing: f64,
+    ell: &Ellipsoid,
+    deg: bool,
+) -> Option<(f64, f64)> {
+    utm_projection(zone, northern, ell)?.inverse(easting, northing, deg)
+}
@@ -1,6 +1,6 @@
 use crate::ellipsoid::Ellipsoid;
 use std::f64::{
-    consts::{FRAC_2_PI, PI, TAU},
+    consts::{FRAC_PI_2, PI, TAU},
     NAN,
 };
 
@@ -17,7 +17,7 @@ pub fn vdist(
     let mut lat2 = if deg { lat2.to_radians() } else { lat2 };
     let mut lon2 = if deg { lon2.to_radians() } else { lon2 };
 
-    /*if lat1.abs() > FRAC_2_PI || lat2.abs() > FRAC_2_PI {
+    /*if lat1.abs() > FRAC_PI_2 || lat2.abs() > FRAC_PI_2 {
         return None;
     }*/
 
@@ -25,12 +25,12 @@ pub fn vdist(
     let b = ell.semiminor_axis;
     let f = ell.flattening;
 
-    if (FRAC_2_PI - lat1.abs()).abs() < 1e-10 {
-        lat1 = lat1.signum() * (FRAC_2_PI - 1e-10);
+    if (FRAC_PI_2 - lat1.abs()).abs() < 1e-10 {
+        lat1 = lat1.signum() * (FRAC_PI_2 - 1e-10);
     }
 
-    if (FRAC_2_PI - lat2.abs()).abs() < 1e-10 {
-        lat2 = lat2.signum() * (FRAC_2_PI - 1e-10);
+    if (FRAC_PI_2 - lat2.abs()).abs() < 1e-10 {
+        lat2 = lat2.signum() * (FRAC_PI_2 - 1e-10);
     }
 
     let u1 = ((1. - f) * lat1.tan()).atan();
@@ -38,7 +38,7 @@ pub fn vdist(
     lon1 = lon1.rem_euclid(TAU);
     lon2 = lon2.rem_euclid(TAU);
 
-    let mut l = lon2 - lon1;
+    let mut l = (lon2 - lon1).rem_euclid(TAU);
 
     if l > PI {
         l = TAU - l;
@@ -55,7 +55,7 @@ pub fn vdist(
         let sin_sigma = ((u2.cos() * lambda.sin()).powi(2)
             + (u1.cos() * u2.sin() - u1.sin() * u2.cos() * lambda.cos()).powi(2))
         .sqrt();
-        let cos_sigma = u1.sin() * u2.sin() + u1.cos() * u1.cos() * lambda.cos();
+        let cos_sigma = u1.sin() * u2.sin() + u1.cos() * u2.cos() * lambda.cos();
         sigma = sin_sigma.atan2(cos_sigma);
 
         let sin_alpha = u1.cos() * u2.cos() * lambda.sin() / sin_sigma;
@@ -63,7 +63,7 @@ pub fn vdist(
         alpha = if sin_alpha.is_nan() {
             0.
         } else if sin_alpha > 1. || (sin_alpha - 1.).abs() < 1e-16 {
-            FRAC_2_PI
+            FRAC_PI_2
         } else {
             sin_alpha.asin()
         };
@@ -138,7 +138,7 @@ pub fn vreckon(
     let lon1 = if deg { lon1.to_radians() } else { lon1 };
     let azim = if deg { azim.to_radians() } else { azim };
 
-    if lat1.abs() > FRAC_2_PI {
+    if lat1.abs() > FRAC_PI_2 {
         return None;
     }
 
@@ -146,8 +146,8 @@ pub fn vreckon(
     let b = ell.semiminor_axis;
     let f = ell.flattening;
 
-    if (FRAC_2_PI - lat1.abs()).abs() < 1e-10 {
-        lat1 = lat1.signum() * (FRAC_2_PI - 1e-10);
+    if (FRAC_PI_2 - lat1.abs()).abs() < 1e-10 {
+        lat1 = lat1.signum() * (FRAC_PI_2 - 1e-10);
     }
 
     let alpha1 = azim;
@@ -226,7 +226,7 @@ pub fn track2(
     let lat2 = if deg { lat2.to_radians() } else { lat2 };
     let lon2 = if deg { lon2.to_radians() } else { lon2 };
 
-    if lat1.abs() > FRAC_2_PI || lat2.abs() > FRAC_2_PI {
+    if lat1.abs() > FRAC_PI_2 || lat2.abs() > FRAC_PI_2 {
         return None;
     }
 
@@ -254,7 +254,7 @@ pub fn track2(
             let mut pts = Vec::with_capacity(npts);
             pts.push((lat1, lon1));
 
-            for _ in 1..=npts {
+            for _ in 1..npts - 1 {
                 let (latptnew, lonptnew) = vreckon(latpt, lonpt, incdist, azimuth, ell, false)?;
                 azimuth = vdist(latptnew, lonptnew, lat2, lon2, ell, false)?.1;
                 pts.push((latptnew, lonptnew));
@@ -0,0 +1,81 @@
+use maprs3d::AzimuthalEquidistant;
+
+#[test]
+fn test_distance() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let aeqd = AzimuthalEquidistant::new(0., 0., 0., 0., &ell, true).unwrap();
+
+    let (x, y) = aeqd.forward(0., 90., true).unwrap();
+    assert!(maprs3d::is_close(x, 10018754.171, 0., 1e-3));
+    assert!(maprs3d::is_close(y, 0., 0., 1e-6));
+
+    let (x, y) = aeqd.forward(89.999, 0., true).unwrap();
+    assert!(maprs3d::is_close(x, 0., 0., 1e-6));
+    assert!(maprs3d::is_close(y, 10001854.5, 0., 1.));
+
+    assert_eq!(aeqd.forward(0., 0., true), Some((0., 0.)));
+    assert_eq!(aeqd.inverse(0., 0., true), Some((0., 0.)));
+}
+
+#[test]
+fn test_roundtrip() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let aeqd = AzimuthalEquidistant::new(52., 13., 100000., 200000., &ell, true).unwrap();
+
+    for &(lat, lon) in &[
+        (52.1, 13.1),
+        (40., -3.),
+        (-20., 20.),
+        (75., 100.),
+        (10., 80.),
+        (89., -120.),
+        (0., -60.),
+    ] {
+        let (x, y) = aeqd.forward(lat, lon, true).unwrap();
+        let (s, az) = aeqd.distance_azimuth(lat, lon, true).unwrap();
+        assert!(maprs3d::is_close(
+            (x - 100000.).hypot(y - 200000.),
+            s,
+            1e-12,
+            1e-6
+        ));
+        assert!(maprs3d::is_close(
+            aeqd.azimuth(lat, lon, true).unwrap(),
+            az,
+            0.,
+            1e-12
+        ));
+
+        let (lat1, lon1) = aeqd.inverse(x, y, true).unwrap();
+        assert!(maprs3d::is_close(lat1, lat, 0., 1e-9));
+        assert!(maprs3d::is_close(lon1, lon, 0., 1e-9));
+    }
+}
+
+#[test]
+fn test_scale() {
+    let sphere = maprs3d::Ellipsoid::new(6371000., 6371000., "sphere", "sphere");
+    let aeqd = AzimuthalEquidistant::new(30., 40., 0., 0., &sphere, true).unwrap();
+
+    assert_eq!(aeqd.scale(30., 40., true), Some(1.));
+
+    for &(lat, lon) in &[(35., 45.), (-10., 60.), (70., -20.)] {
+        let (s, _) = aeqd.distance_azimuth(lat, lon, true).unwrap();
+        let sigma = s / 6371000.;
+        let k = aeqd.scale(lat, lon, true).unwrap();
+        assert!(maprs3d::is_close(k, sigma / sigma.sin(), 1e-7, 0.));
+    }
+
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let aeqd = AzimuthalEquidistant::new(0., 0., 0., 0., &ell, true).unwrap();
+    let sigma = std::f64::consts::FRAC_PI_2 / (1. - ell.flattening);
+    let k = aeqd.scale(0., 90., true).unwrap();
+    assert!(maprs3d::is_close(k, sigma / sigma.sin(), 1e-7, 0.));
+    assert!(maprs3d::is_close(
+        aeqd.convergence(0., 10., true).unwrap(),
+        0.,
+        0.,
+        1e-6
+    ));
+    assert!(aeqd.convergence(30., 30., true).unwrap() > 0.);
+}
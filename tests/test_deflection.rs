@@ -0,0 +1,111 @@
+fn tilted_geoid() -> maprs3d::GeoidGrid {
+    let (nlat, nlon) = (11, 11);
+    let data = (0..nlat * nlon)
+        .map(|k| {
+            let (row, col) = (k / nlon, k % nlon);
+            (20. + 0.5 * row as f64 - 0.25 * col as f64) as f32
+        })
+        .collect();
+
+    maprs3d::GeoidGrid::new(40., 10., 1., 1., nlat, nlon, data).unwrap()
+}
+
+#[test]
+fn test_deflection() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let geoid = tilted_geoid();
+
+    let (xi, eta) = maprs3d::deflection(45.3, 15.6, &geoid, &ell, true).unwrap();
+    let lat = 45.3_f64.to_radians();
+    let m = maprs3d::meridian(lat, &ell, false).unwrap();
+    let n = maprs3d::transverse(lat, &ell, false).unwrap();
+
+    let xi_ref = -0.5 / 1_f64.to_radians() / m;
+    let eta_ref = 0.25 / 1_f64.to_radians() / (n * lat.cos());
+    assert!(maprs3d::is_close(xi, xi_ref.to_degrees(), 1e-6, 0.));
+    assert!(maprs3d::is_close(eta, eta_ref.to_degrees(), 1e-6, 0.));
+
+    assert!(maprs3d::deflection(30., 15., &geoid, &ell, true).is_none());
+}
+
+#[test]
+fn test_astronomic() {
+    let deflection = (5. / 3600., -8. / 3600.);
+
+    let (alat, alon) = maprs3d::geodetic2astronomic(47., 8., deflection, true).unwrap();
+    assert!(maprs3d::is_close(alat, 47. + 5. / 3600., 1e-12, 0.));
+    assert!(maprs3d::is_close(
+        alon,
+        8. - 8. / 3600. / 47_f64.to_radians().cos(),
+        1e-12,
+        0.
+    ));
+
+    let (lat, lon) = maprs3d::astronomic2geodetic(alat, alon, deflection, true).unwrap();
+    assert!(maprs3d::is_close(lat, 47., 1e-12, 0.));
+    assert!(maprs3d::is_close(lon, 8., 1e-12, 0.));
+}
+
+#[test]
+fn test_laplace() {
+    let deflection = (5. / 3600., -8. / 3600.);
+    let lat = 47.;
+
+    for (az, el) in [(30., 2.), (135., -5.), (250., 20.), (0., 0.)] {
+        let (gaz, gel) = maprs3d::astronomic2geodetic_azel(az, el, lat, deflection, true).unwrap();
+        let laz = maprs3d::laplace_azimuth(az, el, lat, deflection, true).unwrap();
+        assert!(maprs3d::is_close(gaz, laz, 0., 1e-6));
+
+        let a = az.to_radians();
+        let dz = deflection.0 * a.cos() + deflection.1 * a.sin();
+        assert!(maprs3d::is_close(gel, el - dz, 0., 1e-6));
+
+        let (aaz, ael) =
+            maprs3d::geodetic2astronomic_azel(gaz, gel, lat, deflection, true).unwrap();
+        assert!(
+            maprs3d::is_close(aaz, az, 0., 1e-9) || maprs3d::is_close(aaz, az + 360., 0., 1e-9)
+        );
+        assert!(maprs3d::is_close(ael, el, 0., 1e-9));
+    }
+}
+
+#[test]
+fn test_frames() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let deflection = (5. / 3600., -8. / 3600.);
+    let (lat0, lon0, h0) = (47., 8., 500.);
+
+    let xyz = maprs3d::enu2ecef_astronomic(100., 200., 30., lat0, lon0, h0, deflection, &ell, true)
+        .unwrap();
+    let enu =
+        maprs3d::ecef2enu_astronomic(xyz.0, xyz.1, xyz.2, lat0, lon0, h0, deflection, &ell, true)
+            .unwrap();
+    assert!(maprs3d::is_close(enu.0, 100., 0., 1e-6));
+    assert!(maprs3d::is_close(enu.1, 200., 0., 1e-6));
+    assert!(maprs3d::is_close(enu.2, 30., 0., 1e-6));
+
+    let ned =
+        maprs3d::ecef2ned_astronomic(xyz.0, xyz.1, xyz.2, lat0, lon0, h0, deflection, &ell, true)
+            .unwrap();
+    assert!(maprs3d::is_close(ned.0, 200., 0., 1e-6));
+    let xyz2 =
+        maprs3d::ned2ecef_astronomic(ned.0, ned.1, ned.2, lat0, lon0, h0, deflection, &ell, true)
+            .unwrap();
+    assert!(maprs3d::is_close(xyz.0, xyz2.0, 0., 1e-6));
+
+    let lla =
+        maprs3d::aer2geodetic_astronomic(60., 10., 5000., lat0, lon0, h0, deflection, &ell, true)
+            .unwrap();
+    let aer = maprs3d::geodetic2aer_astronomic(
+        lla.0, lla.1, lla.2, lat0, lon0, h0, deflection, &ell, true,
+    )
+    .unwrap();
+    assert!(maprs3d::is_close(aer.0, 60., 0., 1e-6));
+    assert!(maprs3d::is_close(aer.1, 10., 0., 1e-6));
+    assert!(maprs3d::is_close(aer.2, 5000., 0., 1e-6));
+
+    let geodetic = maprs3d::geodetic2aer(lla.0, lla.1, lla.2, lat0, lon0, h0, &ell, true).unwrap();
+    let (gaz, gel) = maprs3d::astronomic2geodetic_azel(60., 10., lat0, deflection, true).unwrap();
+    assert!(maprs3d::is_close(geodetic.0, gaz, 0., 1e-6));
+    assert!(maprs3d::is_close(geodetic.1, gel, 0., 1e-6));
+}
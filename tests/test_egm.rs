@@ -0,0 +1,127 @@
+use std::io::Cursor;
+
+const GM: f64 = 3.986004418e14;
+const GAMMA_P: f64 = 9.8321849378;
+
+fn normal_model(max_degree: usize) -> maprs3d::GravityModel {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let mut model = maprs3d::GravityModel::new(GM, ell.semimajor_axis, max_degree, "normal");
+
+    model.set_coefficient(2, 0, -0.484166774985e-3, 0.).unwrap();
+    model.set_coefficient(4, 0, 0.790303733511e-6, 0.).unwrap();
+    model.set_coefficient(6, 0, -0.168724961151e-8, 0.).unwrap();
+    model.set_coefficient(8, 0, 0.346052468394e-11, 0.).unwrap();
+    model
+        .set_coefficient(10, 0, -0.265002225747e-14, 0.)
+        .unwrap();
+
+    model
+}
+
+#[test]
+fn test_normal_field() {
+    let model = normal_model(12);
+
+    for (lat, lon) in [(0., 0.), (45., 30.), (-60., -120.), (89.9, 10.), (-90., 0.)] {
+        let n = model.undulation(lat, lon, true).unwrap();
+        assert!(n.abs() < 1e-4, "{} {} {}", lat, lon, n);
+    }
+
+    assert!(model.undulation(91., 0., true).is_none());
+
+    let grs80 = maprs3d::Ellipsoid::grs80();
+    let shifted = model.clone().with_ellipsoid(&grs80);
+    assert_eq!(shifted.ellipsoid().model, "grs80");
+    assert_eq!(
+        model.clone().with_ellipsoid(&maprs3d::Ellipsoid::wgs84()),
+        model
+    );
+
+    let n = shifted.undulation(45., 0., true).unwrap();
+    assert!(n.abs() > 1e-4 && n.abs() < 1., "{}", n);
+}
+
+#[test]
+fn test_high_degree() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let a = ell.semimajor_axis;
+    let b = ell.semiminor_axis;
+
+    let mut model = normal_model(2190);
+    model.set_coefficient(2000, 0, 1e-9, 0.).unwrap();
+
+    let expected = GM / b * (a / b).powi(2000) * 1e-9 * 4001_f64.sqrt() / GAMMA_P;
+    let n = model.undulation(90., 0., true).unwrap();
+    assert!(maprs3d::is_close(n, expected, 1e-8, 0.));
+
+    model.set_coefficient(2190, 2190, 1e-9, 1e-9).unwrap();
+    for lat in [-89.999, -45., 0., 30., 89.999] {
+        assert!(model.undulation(lat, 17., true).unwrap().is_finite());
+    }
+}
+
+#[test]
+fn test_sectoral() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let mut model = normal_model(10);
+    model.set_coefficient(2, 2, 2e-6, -1e-6).unwrap();
+
+    let (lat, lon) = (30_f64, 40_f64);
+    let (x, y, z) = maprs3d::geodetic2ecef(lat, lon, 0., &ell, true).unwrap();
+    let r = x.hypot(y).hypot(z);
+    let cos_lat = x.hypot(y) / r;
+    let lon = lon.to_radians();
+
+    let p22 = 15_f64.sqrt() / 2. * cos_lat.powi(2);
+    let t = GM / r
+        * (ell.semimajor_axis / r).powi(2)
+        * p22
+        * (2e-6 * (2. * lon).cos() - 1e-6 * (2. * lon).sin());
+
+    let n = model.undulation(30., 40., true).unwrap();
+
+    let gamma = 9.7803253359 * (1. + 0.00193185265241 * lat.to_radians().sin().powi(2))
+        / (1. - 0.00669437999014 * lat.to_radians().sin().powi(2)).sqrt();
+    assert!(maprs3d::is_close(n, t / gamma, 1e-6, 1e-6));
+}
+
+#[test]
+fn test_gfc() {
+    let text = "\
+product_type          gravity_field
+modelname             TEST
+earth_gravity_constant 0.3986004415E+15
+radius                0.63781363E+07
+max_degree            3
+norm                  fully_normalized
+key  L  M  C  S
+end_of_head ====================================
+gfc  0  0  1.0D+00  0.0
+gfc  2  0 -0.484165371736E-03  0.000000000000E+00
+gfc  2  1 -0.186987635955E-09  0.119528012031E-08
+gfc  2  2  0.243914352398E-05 -0.140016683654E-05
+gfc  3  3  0.100558857940E-06  0.144820458000E-06
+";
+
+    let model = maprs3d::GravityModel::from_gfc(Cursor::new(text), 360).unwrap();
+    assert_eq!(model.name, "TEST");
+    assert_eq!(model.max_degree(), 3);
+    assert_eq!(model.gm(), 3.986004415e14);
+    assert_eq!(model.radius(), 6378136.3);
+    assert_eq!(model.coefficient(0, 0), Some((1., 0.)));
+    assert_eq!(
+        model.coefficient(2, 2),
+        Some((0.243914352398E-05, -0.140016683654E-05))
+    );
+    assert!(model.coefficient(4, 0).is_none());
+
+    let truncated = maprs3d::GravityModel::from_gfc(Cursor::new(text), 2).unwrap();
+    assert_eq!(truncated.max_degree(), 2);
+
+    let raw = "2 0 -0.484165371736D-03 0.0 0.0 0.0\n2 1 -0.18698763595D-09 0.11952801203D-08\n";
+    let model = maprs3d::GravityModel::from_gfc(Cursor::new(raw), 360).unwrap();
+    assert_eq!(model.max_degree(), 2);
+
+    let bad = "gfc 2 3 1.0 0.0\n";
+    assert!(maprs3d::GravityModel::from_gfc(Cursor::new(bad), 360).is_err());
+}
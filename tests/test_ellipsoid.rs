@@ -9,6 +9,7 @@ fn test_reference() {
         ("everest1830m", 0.003324449295589469),
         ("everest1967", 0.003324449343845343),
         ("airy", 0.00334085067870327),
+        ("airy_modified", 0.0033408506318589907),
         ("bessel", 0.0033427731536659813),
         ("clarke1866", 0.0033900753039287908),
         ("clarke1878", 0.003407549790771363),
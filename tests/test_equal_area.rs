@@ -0,0 +1,152 @@
+use maprs3d::{AlbersEqualArea, LambertAzimuthalEqualArea};
+
+fn q(lat: f64, ell: &maprs3d::Ellipsoid) -> f64 {
+    let e = ell.eccentricity;
+    let s = lat.to_radians().sin();
+
+    (1. - e * e) * (s / (1. - (e * s).powi(2)) - ((1. - e * s) / (1. + e * s)).ln() / (2. * e))
+}
+
+fn ellipsoidal_area(lat1: f64, lat2: f64, dlon: f64, ell: &maprs3d::Ellipsoid) -> f64 {
+    ell.semimajor_axis.powi(2) * dlon.to_radians() * (q(lat2, ell) - q(lat1, ell)) / 2.
+}
+
+fn projected_area<F>(lat1: f64, lat2: f64, lon1: f64, lon2: f64, forward: F) -> f64
+where
+    F: Fn(f64, f64) -> (f64, f64),
+{
+    let steps = 2000;
+    let mut ring = Vec::new();
+
+    for i in 0..steps {
+        ring.push(forward(
+            lat1,
+            lon1 + (lon2 - lon1) * i as f64 / steps as f64,
+        ));
+    }
+    for i in 0..steps {
+        ring.push(forward(
+            lat1 + (lat2 - lat1) * i as f64 / steps as f64,
+            lon2,
+        ));
+    }
+    for i in 0..steps {
+        ring.push(forward(
+            lat2,
+            lon2 - (lon2 - lon1) * i as f64 / steps as f64,
+        ));
+    }
+    for i in 0..steps {
+        ring.push(forward(
+            lat2 - (lat2 - lat1) * i as f64 / steps as f64,
+            lon1,
+        ));
+    }
+
+    let mut area = 0.;
+    for i in 0..ring.len() {
+        let (x1, y1) = ring[i];
+        let (x2, y2) = ring[(i + 1) % ring.len()];
+        area += x1 * y2 - x2 * y1;
+    }
+
+    area.abs() / 2.
+}
+
+#[test]
+fn test_albers() {
+    let ell = maprs3d::Ellipsoid::clarke1866();
+    let albers = AlbersEqualArea::new(29.5, 45.5, 23., -96., 0., 0., &ell, true).unwrap();
+
+    let (x, y) = albers.forward(35., -75., true).unwrap();
+    assert!(maprs3d::is_close(x, 1885472.7, 0., 0.1));
+    assert!(maprs3d::is_close(y, 1535925.0, 0., 0.1));
+
+    let (lat, lon) = albers.inverse(x, y, true).unwrap();
+    assert!(maprs3d::is_close(lat, 35., 0., 1e-8));
+    assert!(maprs3d::is_close(lon, -75., 0., 1e-10));
+
+    assert!(maprs3d::is_close(
+        albers.scale(29.5, 0., true).unwrap(),
+        1.,
+        0.,
+        1e-8
+    ));
+    assert!(maprs3d::is_close(
+        albers.scale(45.5, 0., true).unwrap(),
+        1.,
+        0.,
+        1e-8
+    ));
+    assert!(albers.scale(37., 0., true).unwrap() < 1.);
+
+    let south = AlbersEqualArea::new(-18., -36., 0., 132., 0., 0., &ell, true).unwrap();
+    let (x, y) = south.forward(-30., 140., true).unwrap();
+    let (lat, lon) = south.inverse(x, y, true).unwrap();
+    assert!(maprs3d::is_close(lat, -30., 0., 1e-8));
+    assert!(maprs3d::is_close(lon, 140., 0., 1e-10));
+}
+
+#[test]
+fn test_albers_area() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let albers = AlbersEqualArea::new(29.5, 45.5, 23., -96., 0., 0., &ell, true).unwrap();
+
+    let area = projected_area(30., 40., -100., -90., |lat, lon| {
+        albers.forward(lat, lon, true).unwrap()
+    });
+    let expected = ellipsoidal_area(30., 40., 10., &ell);
+    assert!(maprs3d::is_close(area, expected, 1e-6, 0.));
+
+    let area = projected_area(-10., 70., -150., -40., |lat, lon| {
+        albers.forward(lat, lon, true).unwrap()
+    });
+    let expected = ellipsoidal_area(-10., 70., 110., &ell);
+    assert!(maprs3d::is_close(area, expected, 1e-6, 0.));
+}
+
+#[test]
+fn test_laea() {
+    let laea = LambertAzimuthalEqualArea::etrs89();
+
+    let (x, y) = laea.forward(50., 5., true).unwrap();
+    assert!(maprs3d::is_close(x, 3962799.45, 0., 1e-2));
+    assert!(maprs3d::is_close(y, 2999718.85, 0., 1e-2));
+
+    for &(lat, lon) in &[(50., 5.), (52., 10.), (70., 40.), (30., -20.), (-30., 60.)] {
+        let (x, y) = laea.forward(lat, lon, true).unwrap();
+        let (lat1, lon1) = laea.inverse(x, y, true).unwrap();
+        assert!(maprs3d::is_close(lat1, lat, 0., 2e-8));
+        assert!(maprs3d::is_close(lon1, lon, 0., 1e-10));
+    }
+
+    assert!(laea.forward(-52., -170., true).is_none());
+
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let polar = LambertAzimuthalEqualArea::new(90., 0., 0., 0., &ell, true).unwrap();
+    let (x, y) = polar.forward(60., 90., true).unwrap();
+    assert!(x > 0.);
+    assert!(maprs3d::is_close(y, 0., 0., 1e-6));
+    let (lat, lon) = polar.inverse(x, y, true).unwrap();
+    assert!(maprs3d::is_close(lat, 60., 0., 1e-8));
+    assert!(maprs3d::is_close(lon, 90., 0., 1e-10));
+}
+
+#[test]
+fn test_laea_area() {
+    let ell = maprs3d::Ellipsoid::grs80();
+    let laea = LambertAzimuthalEqualArea::etrs89();
+
+    let area = projected_area(45., 55., 0., 20., |lat, lon| {
+        laea.forward(lat, lon, true).unwrap()
+    });
+    let expected = ellipsoidal_area(45., 55., 20., &ell);
+    assert!(maprs3d::is_close(area, expected, 1e-6, 0.));
+
+    let polar = LambertAzimuthalEqualArea::new(-90., 0., 0., 0., &ell, true).unwrap();
+    let area = projected_area(-80., -60., 30., 120., |lat, lon| {
+        polar.forward(lat, lon, true).unwrap()
+    });
+    let expected = ellipsoidal_area(-80., -60., 90., &ell);
+    assert!(maprs3d::is_close(area, expected, 1e-6, 0.));
+}
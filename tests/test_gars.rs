@@ -0,0 +1,88 @@
+use maprs3d::{gars2geodetic, gars_bbox, gars_cells, gars_polygon, geodetic2gars};
+
+#[test]
+fn test_encode() {
+    assert_eq!(geodetic2gars(-86.95, -177.27, 5, true).unwrap(), "006AG39");
+    assert_eq!(geodetic2gars(-86.95, -177.27, 15, true).unwrap(), "006AG3");
+    assert_eq!(geodetic2gars(-86.95, -177.27, 30, true).unwrap(), "006AG");
+
+    assert_eq!(geodetic2gars(-90., -180., 5, true).unwrap(), "001AA37");
+    assert_eq!(geodetic2gars(90., 179.99, 5, true).unwrap(), "720QZ23");
+    assert_eq!(geodetic2gars(0., 0., 30, true).unwrap(), "361HN");
+    assert_eq!(geodetic2gars(0., 180., 30, true).unwrap(), "001HN");
+
+    assert_eq!(
+        geodetic2gars(38.89f64.to_radians(), (-77.03f64).to_radians(), 5, false).unwrap(),
+        geodetic2gars(38.89, -77.03, 5, true).unwrap()
+    );
+
+    assert!(geodetic2gars(91., 0., 5, true).is_none());
+    assert!(geodetic2gars(0., 0., 10, true).is_none());
+}
+
+#[test]
+fn test_decode() {
+    let (south, west, north, east) = gars_bbox("006AG39", true).unwrap();
+    assert!(maprs3d::is_close(south, -87., 0., 1e-12));
+    assert!(maprs3d::is_close(west, -177. - 20. / 60., 0., 1e-12));
+    assert!(maprs3d::is_close(north, -87. + 5. / 60., 0., 1e-12));
+    assert!(maprs3d::is_close(east, -177.25, 0., 1e-12));
+
+    let (south, west, north, east) = gars_bbox("006ag1", true).unwrap();
+    assert!(maprs3d::is_close(south, -86.75, 0., 1e-12));
+    assert!(maprs3d::is_close(west, -177.5, 0., 1e-12));
+    assert!(maprs3d::is_close(north, -86.5, 0., 1e-12));
+    assert!(maprs3d::is_close(east, -177.25, 0., 1e-12));
+
+    let (lat, lon) = gars2geodetic("361HN", true).unwrap();
+    assert!(maprs3d::is_close(lat, 0.25, 0., 1e-12));
+    assert!(maprs3d::is_close(lon, 0.25, 0., 1e-12));
+
+    let polygon = gars_polygon("361HN", true).unwrap();
+    assert_eq!(polygon, [(0., 0.), (0., 0.5), (0.5, 0.5), (0.5, 0.)]);
+
+    for minutes in [30, 15, 5] {
+        for &(lat, lon) in &[(38.89, -77.03), (-33.87, 151.21), (71.3, -156.8)] {
+            let code = geodetic2gars(lat, lon, minutes, true).unwrap();
+            let (south, west, north, east) = gars_bbox(&code, true).unwrap();
+            assert!(
+                south <= lat && lat < north && west <= lon && lon < east,
+                "{}",
+                code
+            );
+
+            let (clat, clon) = gars2geodetic(&code, true).unwrap();
+            assert_eq!(geodetic2gars(clat, clon, minutes, true).unwrap(), code);
+        }
+    }
+
+    for code in [
+        "000AA", "721AA", "001RA", "001AI", "001AA5", "001AA10", "01AA", "0a1AA",
+    ] {
+        assert!(gars_bbox(code, true).is_none(), "{}", code);
+    }
+}
+
+#[test]
+fn test_cells() {
+    let cells = gars_cells(0., 0., 1., 1., 30, true).unwrap();
+    assert_eq!(cells, vec!["361HN", "362HN", "361HP", "362HP"]);
+
+    let cells = gars_cells(0.1, 0.1, 0.2, 0.2, 5, true).unwrap();
+    assert_eq!(cells.len(), 4);
+    assert!(cells.contains(&geodetic2gars(0.15, 0.15, 5, true).unwrap()));
+
+    let cells = gars_cells(10., 179.6, 10.4, -179.6, 30, true).unwrap();
+    assert_eq!(cells.len(), 2);
+    assert!(cells.contains(&"720JJ".to_string()));
+    assert!(cells.contains(&"001JJ".to_string()));
+
+    let cells = gars_cells(38.89, -77.03, 38.89, -77.03, 15, true).unwrap();
+    assert_eq!(cells, vec![geodetic2gars(38.89, -77.03, 15, true).unwrap()]);
+
+    let cells = gars_cells(-90., -180., 90., 180., 30, true).unwrap();
+    assert_eq!(cells.len(), 720 * 360);
+
+    assert!(gars_cells(10., 0., 5., 1., 30, true).is_none());
+    assert!(gars_cells(-90., -180., 90., 180., 5, true).is_none());
+}
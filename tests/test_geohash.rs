@@ -0,0 +1,161 @@
+use maprs3d::{
+    geodetic2geohash, geohash2geodetic, geohash_bbox, geohash_circle, geohash_neighbours,
+    geohash_polygon,
+};
+
+#[test]
+fn test_encode() {
+    assert_eq!(
+        geodetic2geohash(57.64911, 10.40744, 11, true).unwrap(),
+        "u4pruydqqvj"
+    );
+    assert_eq!(geodetic2geohash(42.6, -5.6, 5, true).unwrap(), "ezs42");
+    assert_eq!(geodetic2geohash(-90., -180., 3, true).unwrap(), "000");
+    assert_eq!(geodetic2geohash(90., 180., 3, true).unwrap(), "bpb");
+
+    let hash = geodetic2geohash(42.6f64.to_radians(), -5.6f64.to_radians(), 5, false).unwrap();
+    assert_eq!(hash, "ezs42");
+
+    assert!(geodetic2geohash(91., 0., 5, true).is_none());
+    assert!(geodetic2geohash(0., 0., 0, true).is_none());
+    assert!(geodetic2geohash(0., 0., 13, true).is_none());
+}
+
+#[test]
+fn test_decode() {
+    let (south, west, north, east) = geohash_bbox("ezs42", true).unwrap();
+    assert!(maprs3d::is_close(south, 42.5830078125, 0., 1e-12));
+    assert!(maprs3d::is_close(west, -5.625, 0., 1e-12));
+    assert!(maprs3d::is_close(north, 42.626953125, 0., 1e-12));
+    assert!(maprs3d::is_close(east, -5.5810546875, 0., 1e-12));
+
+    let (lat, lon) = geohash2geodetic("EZS42", true).unwrap();
+    assert!(maprs3d::is_close(lat, 42.60498046875, 0., 1e-12));
+    assert!(maprs3d::is_close(lon, -5.60302734375, 0., 1e-12));
+
+    for precision in 1..=12 {
+        let hash = geodetic2geohash(-33.8688, 151.2093, precision, true).unwrap();
+        let (south, west, north, east) = geohash_bbox(&hash, true).unwrap();
+        assert!(south <= -33.8688 && -33.8688 < north);
+        assert!(west <= 151.2093 && 151.2093 < east);
+
+        let (lat, lon) = geohash2geodetic(&hash, true).unwrap();
+        assert_eq!(geodetic2geohash(lat, lon, precision, true).unwrap(), hash);
+    }
+
+    assert!(geohash_bbox("", true).is_none());
+    assert!(geohash_bbox("ezs4a", true).is_none());
+}
+
+#[test]
+fn test_neighbours() {
+    let hash = "ezs42";
+    let (south, west, north, east) = geohash_bbox(hash, true).unwrap();
+    let (dlat, dlon) = (north - south, east - west);
+    let (lat, lon) = geohash2geodetic(hash, true).unwrap();
+
+    let neighbours = geohash_neighbours(hash).unwrap();
+    let offsets = [
+        (1., 0.),
+        (1., 1.),
+        (0., 1.),
+        (-1., 1.),
+        (-1., 0.),
+        (-1., -1.),
+        (0., -1.),
+        (1., -1.),
+    ];
+
+    for (neighbour, (n, e)) in neighbours.iter().zip(offsets) {
+        let expected = geodetic2geohash(lat + n * dlat, lon + e * dlon, 5, true).unwrap();
+        assert_eq!(neighbour.as_deref(), Some(expected.as_str()));
+    }
+
+    let hash = geodetic2geohash(10., 179.99, 4, true).unwrap();
+    let neighbours = geohash_neighbours(&hash).unwrap();
+    let (_, west, _, _) = geohash_bbox(neighbours[2].as_ref().unwrap(), true).unwrap();
+    assert_eq!(west, -180.);
+    assert_eq!(
+        neighbours[2].as_deref(),
+        geodetic2geohash(10., -179.99, 4, true).as_deref()
+    );
+
+    let hash = geodetic2geohash(89.99, 0., 4, true).unwrap();
+    let neighbours = geohash_neighbours(&hash).unwrap();
+    assert!(neighbours[0].is_none() && neighbours[1].is_none() && neighbours[7].is_none());
+    assert!(neighbours[4].is_some());
+
+    assert!(geohash_neighbours("ai").is_none());
+}
+
+#[test]
+fn test_circle() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    for &(lat, lon, radius, precision) in &[
+        (51.5, -0.12, 2000., 6),
+        (-0.001, 179.999, 5000., 5),
+        (89.9, 45., 20000., 4),
+    ] {
+        let cells = geohash_circle(lat, lon, radius, precision, &ell, true).unwrap();
+
+        assert!(cells.contains(&geodetic2geohash(lat, lon, precision, true).unwrap()));
+
+        for i in 0..72 {
+            for &fraction in &[0.5, 0.999] {
+                let (lat1, lon1) =
+                    maprs3d::vreckon(lat, lon, radius * fraction, 5. * i as f64, &ell, true)
+                        .unwrap();
+                let hash = geodetic2geohash(lat1, lon1, precision, true).unwrap();
+                assert!(cells.contains(&hash), "{} {} {}", lat1, lon1, hash);
+            }
+        }
+
+        for hash in &cells {
+            let (s, w, n, e) = geohash_bbox(hash, true).unwrap();
+            let corners = [(s, w), (s, e), (n, w), (n, e), ((s + n) / 2., (w + e) / 2.)];
+            let nearest = corners
+                .iter()
+                .map(|&(a, b)| maprs3d::vdist(lat, lon, a, b, &ell, true).unwrap().0)
+                .fold(f64::INFINITY, f64::min);
+            assert!(nearest < radius + 2. * maprs3d::vdist(s, w, n, e, &ell, true).unwrap().0);
+        }
+    }
+
+    let cells = geohash_circle(0., 0., 1., 1, &ell, true).unwrap();
+    assert_eq!(cells.len(), 4);
+
+    assert!(geohash_circle(0., 0., 1e6, 12, &ell, true).is_none());
+    assert!(geohash_circle(0., 0., -1., 5, &ell, true).is_none());
+}
+
+#[test]
+fn test_polygon() {
+    let square = [(0., 0.), (0., 45.), (45., 45.), (45., 0.)];
+    let cells = geohash_polygon(&square, 1, true).unwrap();
+    assert!(cells.contains(&"s".to_string()));
+    assert!(cells.len() <= 9);
+
+    let triangle = [(10., 170.), (-10., -170.), (20., -175.)];
+    let cells = geohash_polygon(&triangle, 3, true).unwrap();
+
+    for &(lat, lon) in &[
+        (10., 170.),
+        (-10., -170.),
+        (20., -175.),
+        (7., 179.5),
+        (5., -178.),
+    ] {
+        assert!(cells.contains(&geodetic2geohash(lat, lon, 3, true).unwrap()));
+    }
+
+    assert!(!cells.contains(&geodetic2geohash(-20., 175., 3, true).unwrap()));
+    assert!(!cells.contains(&geodetic2geohash(0., 0., 3, true).unwrap()));
+
+    let thin = [(0.001, 0.001), (0.002, 0.001), (0.002, 0.002)];
+    let cells = geohash_polygon(&thin, 9, true).unwrap();
+    assert!(!cells.is_empty());
+
+    assert!(geohash_polygon(&square[..2], 3, true).is_none());
+    assert!(geohash_polygon(&[(80., 0.), (80., 120.), (80., -120.)], 3, true).is_none());
+}
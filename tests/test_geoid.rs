@@ -0,0 +1,135 @@
+use std::io::Cursor;
+
+use maprs3d::Geoid;
+
+fn linear_grid() -> maprs3d::GeoidGrid {
+    let (nlat, nlon) = (5, 7);
+    let data = (0..nlat * nlon)
+        .map(|k| {
+            let (row, col) = (k / nlon, k % nlon);
+            let lat = 40. + row as f64;
+            let lon = -5. + 2. * col as f64;
+            (10. + 0.5 * lat - 0.25 * lon) as f32
+        })
+        .collect();
+
+    maprs3d::GeoidGrid::new(40., -5., 1., 2., nlat, nlon, data).unwrap()
+}
+
+#[test]
+fn test_interpolation() {
+    let grid = linear_grid();
+    let expected = |lat: f64, lon: f64| 10. + 0.5 * lat - 0.25 * lon;
+
+    for (lat, lon) in [(40., -5.), (41.3, 0.7), (43.9, 6.5), (44., 7.)] {
+        let n = grid.undulation(lat, lon, true).unwrap();
+        assert!(maprs3d::is_close(n, expected(lat, lon), 1e-6, 1e-6));
+
+        let n = grid
+            .undulation(lat.to_radians(), lon.to_radians(), false)
+            .unwrap();
+        assert!(maprs3d::is_close(n, expected(lat, lon), 1e-6, 1e-6));
+    }
+
+    let grid = grid.with_interpolation(maprs3d::Interpolation::Bicubic);
+    for (lat, lon) in [(41.3, 0.7), (42.5, 3.1)] {
+        let n = grid.undulation(lat, lon, true).unwrap();
+        assert!(maprs3d::is_close(n, expected(lat, lon), 1e-6, 1e-6));
+    }
+
+    assert!(grid.undulation(39.9, 0., true).is_none());
+    assert!(grid.undulation(42., 7.5, true).is_none());
+    assert!(grid.undulation(42., 350., true).is_none());
+    assert!(grid.undulation(42., 356., true).is_some());
+}
+
+#[test]
+fn test_global_wrap() {
+    let text = "-90 90 0 360 90 90\n\
+                1 1 1 1 1\n\
+                2 4 6 8 2\n\
+                3 3 3 3 3\n";
+
+    let grid = maprs3d::GeoidGrid::from_grd(Cursor::new(text)).unwrap();
+    assert_eq!((grid.nlat, grid.nlon), (3, 5));
+
+    assert_eq!(grid.undulation(90., 0., true), Some(1.));
+    assert_eq!(grid.undulation(-90., 123., true), Some(3.));
+    assert_eq!(grid.undulation(0., 90., true), Some(4.));
+    assert_eq!(grid.undulation(0., -90., true), Some(8.));
+    assert_eq!(grid.undulation(0., 315., true), Some(5.));
+    assert_eq!(grid.undulation(0., -45., true), Some(5.));
+
+    for header in [
+        "0 1e300 0 1e300 1e-300 1e-300",
+        "0 90 0 90 0 45",
+        "0 90 0 90 -45 45",
+        "90 0 0 90 45 45",
+        "0 inf 0 90 45 45",
+        "0 90 0 NaN 45 45",
+        "-90 90 -180 180 1e-3 1e-3",
+    ] {
+        let error = maprs3d::GeoidGrid::from_grd(Cursor::new(header)).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData, "{}", header);
+    }
+}
+
+#[test]
+fn test_gtx() {
+    let mut bytes = Vec::new();
+    for value in [10., 250., 1., 2.] {
+        bytes.extend_from_slice(&f64::to_be_bytes(value));
+    }
+    for value in [2, 3] {
+        bytes.extend_from_slice(&i32::to_be_bytes(value));
+    }
+    for value in [1., 2., 3., 4., 5., -88.8888] {
+        bytes.extend_from_slice(&f32::to_be_bytes(value));
+    }
+
+    let grid = maprs3d::GeoidGrid::from_gtx(Cursor::new(&bytes)).unwrap();
+    assert_eq!(grid.west, -110.);
+    assert_eq!(grid.undulation(10., -110., true), Some(1.));
+    assert_eq!(grid.undulation(10.5, -109.5, true), Some(2.75));
+    assert!(grid.undulation(11., -108., true).is_none());
+
+    let error = maprs3d::GeoidGrid::from_gtx(Cursor::new(&bytes[..50])).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+    let mut trailing = bytes.clone();
+    trailing.extend_from_slice(&[0; 4]);
+    assert!(maprs3d::GeoidGrid::from_gtx(Cursor::new(&trailing)).is_err());
+
+    for (nlat, nlon) in [(0, 3), (2, -3), (i32::MAX, i32::MAX), (1 << 15, 1 << 14)] {
+        let mut header = bytes[..40].to_vec();
+        header[32..36].copy_from_slice(&i32::to_be_bytes(nlat));
+        header[36..40].copy_from_slice(&i32::to_be_bytes(nlon));
+
+        let error = maprs3d::GeoidGrid::from_gtx(Cursor::new(&header)).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
+
+#[test]
+fn test_orthometric() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let grid = maprs3d::GeoidGrid::new(-90., 0., 90., 90., 3, 5, vec![30.; 15]).unwrap();
+
+    let h = maprs3d::ellipsoidal2orthometric(100., 42., -82., &grid, true).unwrap();
+    assert_eq!(h, 70.);
+    let alt = maprs3d::orthometric2ellipsoidal(h, 42., -82., &grid, true).unwrap();
+    assert_eq!(alt, 100.);
+
+    let xyz = maprs3d::geodetic2ecef_orthometric(42., -82., 70., &grid, &ell, true).unwrap();
+    assert_eq!(
+        xyz,
+        maprs3d::geodetic2ecef(42., -82., 100., &ell, true).unwrap()
+    );
+
+    let lla = maprs3d::ecef2geodetic_orthometric(xyz.0, xyz.1, xyz.2, &grid, &ell, true).unwrap();
+    assert!(maprs3d::is_close(lla.2, 70., 1e-6, 1e-6));
+
+    let enu = maprs3d::geodetic2enu_orthometric(42., -82., 70., 42., -82., 0., &grid, &ell, true)
+        .unwrap();
+    assert!(maprs3d::is_close(enu.2, 70., 1e-9, 1e-6));
+}
@@ -0,0 +1,105 @@
+use maprs3d::Gnomonic;
+
+#[test]
+fn test_sphere() {
+    let r = 6371000.;
+    let sphere = maprs3d::Ellipsoid::new(r, r, "sphere", "sphere");
+    let gnomonic = Gnomonic::new(30., 0., &sphere, true).unwrap();
+
+    let (lat, lon, lat0) = (50f64.to_radians(), 20f64.to_radians(), 30f64.to_radians());
+    let c = lat0.sin() * lat.sin() + lat0.cos() * lat.cos() * lon.cos();
+    let x = r * lat.cos() * lon.sin() / c;
+    let y = r * (lat0.cos() * lat.sin() - lat0.sin() * lat.cos() * lon.cos()) / c;
+
+    let (x1, y1) = gnomonic.forward(50., 20., true).unwrap();
+    assert!(maprs3d::is_close(x1, x, 1e-12, 1e-6));
+    assert!(maprs3d::is_close(y1, y, 1e-12, 1e-6));
+
+    assert!(gnomonic.forward(-70., 180., true).is_none());
+}
+
+#[test]
+fn test_roundtrip() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let gnomonic = Gnomonic::new(48.8566, 2.3522, &ell, true).unwrap();
+
+    assert_eq!(gnomonic.forward(48.8566, 2.3522, true), Some((0., 0.)));
+
+    for &(lat, lon) in &[
+        (51.5074, -0.1278),
+        (10., 60.),
+        (80., -100.),
+        (0., 2.),
+        (-20., -30.),
+    ] {
+        let (x, y) = gnomonic.forward(lat, lon, true).unwrap();
+        let (lat1, lon1) = gnomonic.inverse(x, y, true).unwrap();
+        assert!(maprs3d::is_close(lat1, lat, 0., 1e-9));
+        assert!(maprs3d::is_close(lon1, lon, 0., 1e-9));
+    }
+}
+
+#[test]
+fn test_straight_geodesic() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let gnomonic = Gnomonic::new(45., 10., &ell, true).unwrap();
+
+    let (lat1, lon1, lat2, lon2) = (40., 0., 52., 25.);
+    let (s, az) = maprs3d::vdist(lat1, lon1, lat2, lon2, &ell, true).unwrap();
+
+    let (xa, ya) = gnomonic.forward(lat1, lon1, true).unwrap();
+    let (xb, yb) = gnomonic.forward(lat2, lon2, true).unwrap();
+
+    for i in 1..10 {
+        let (lat, lon) = maprs3d::vreckon(lat1, lon1, s * i as f64 / 10., az, &ell, true).unwrap();
+        let (x, y) = gnomonic.forward(lat, lon, true).unwrap();
+        let cross = (xb - xa) * (y - ya) - (yb - ya) * (x - xa);
+        let offset = cross / (xb - xa).hypot(yb - ya);
+        assert!(offset.abs() < 1.);
+    }
+}
+
+#[test]
+fn test_nearest() {
+    let r = 6371000.;
+    let sphere = maprs3d::Ellipsoid::new(r, r, "sphere", "sphere");
+    let (lat, lon, d) =
+        maprs3d::geodesic_nearest(0., -10., 0., 30., 5., 12., &sphere, true).unwrap();
+    assert!(maprs3d::is_close(lat, 0., 0., 1e-9));
+    assert!(maprs3d::is_close(lon, 12., 0., 1e-9));
+    assert!(maprs3d::is_close(d, r * 5f64.to_radians(), 1e-9, 0.));
+
+    for (plat, plon, elat, elon) in [(5., 40., 0., 30.), (-3., -25., 0., -10.)] {
+        let (lat, lon, d) =
+            maprs3d::geodesic_nearest(0., -10., 0., 30., plat, plon, &sphere, true).unwrap();
+        assert!(maprs3d::is_close(lat, elat, 0., 1e-9));
+        assert!(maprs3d::is_close(lon, elon, 0., 1e-9));
+
+        let expected = maprs3d::vdist(elat, elon, plat, plon, &sphere, true)
+            .unwrap()
+            .0;
+        assert!(maprs3d::is_close(d, expected, 1e-9, 0.));
+    }
+
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let (lat1, lon1, lat2, lon2) = (40., 0., 52., 25.);
+    let (lat, lon, d) =
+        maprs3d::geodesic_nearest(lat1, lon1, lat2, lon2, 50., 5., &ell, true).unwrap();
+
+    let s = maprs3d::vdist(lat1, lon1, lat2, lon2, &ell, true)
+        .unwrap()
+        .0;
+    let s1 = maprs3d::vdist(lat1, lon1, lat, lon, &ell, true).unwrap().0;
+    let s2 = maprs3d::vdist(lat, lon, lat2, lon2, &ell, true).unwrap().0;
+    assert!(maprs3d::is_close(s1 + s2, s, 0., 1e-3));
+
+    let (dp, azp) = maprs3d::vdist(lat, lon, 50., 5., &ell, true).unwrap();
+    let azb = maprs3d::vdist(lat, lon, lat2, lon2, &ell, true).unwrap().1;
+    assert!(maprs3d::is_close(dp, d, 0., 1e-6));
+    assert!(maprs3d::is_close(
+        (azp - azb).rem_euclid(360.),
+        270.,
+        0.,
+        1e-6
+    ));
+}
@@ -0,0 +1,109 @@
+#[test]
+fn test_physical() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let physical = ell.physical.unwrap();
+    assert_eq!(physical.gm, 3.986004418e14);
+    assert!(maprs3d::is_close(physical.j2, 1.082629821313e-3, 1e-9, 0.));
+
+    let ell = maprs3d::Ellipsoid::grs80();
+    assert!(maprs3d::is_close(
+        ell.physical.unwrap().j2,
+        1.08263e-3,
+        1e-7,
+        0.
+    ));
+
+    let j = |n| maprs3d::zonal_harmonic(n, &ell).unwrap();
+    assert!(maprs3d::is_close(j(2), ell.physical.unwrap().j2, 1e-12, 0.));
+    assert!(maprs3d::is_close(j(4), -0.237091222e-5, 1e-8, 0.));
+    assert!(maprs3d::is_close(j(6), 0.608347e-8, 1e-6, 0.));
+    assert!(maprs3d::is_close(j(8), -0.1427e-10, 1e-3, 0.));
+    assert!(maprs3d::zonal_harmonic(0, &ell).is_none());
+    assert!(maprs3d::zonal_harmonic(3, &ell).is_none());
+
+    let ell = maprs3d::Ellipsoid::airy();
+    assert!(ell.physical.is_none());
+    assert!(maprs3d::somigliana(45., &ell, true).is_none());
+
+    let ell = ell.with_physical(3.986004418e14, 7.292115e-5);
+    assert!(maprs3d::somigliana(45., &ell, true).is_some());
+}
+
+#[test]
+fn test_somigliana() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    let gamma_e = maprs3d::equatorial_gravity(&ell).unwrap();
+    let gamma_p = maprs3d::polar_gravity(&ell).unwrap();
+    assert!(maprs3d::is_close(gamma_e, 9.7803253359, 1e-10, 0.));
+    assert!(maprs3d::is_close(gamma_p, 9.8321849378, 1e-10, 0.));
+
+    assert!(maprs3d::is_close(
+        maprs3d::somigliana(0., &ell, true).unwrap(),
+        gamma_e,
+        1e-12,
+        0.
+    ));
+    assert!(maprs3d::is_close(
+        maprs3d::somigliana(-90., &ell, true).unwrap(),
+        gamma_p,
+        1e-12,
+        0.
+    ));
+    assert!(maprs3d::somigliana(91., &ell, true).is_none());
+
+    let ell = maprs3d::Ellipsoid::grs80();
+    let gamma_e = maprs3d::equatorial_gravity(&ell).unwrap();
+    assert!(maprs3d::is_close(gamma_e, 9.7803267715, 1e-9, 0.));
+}
+
+#[test]
+fn test_normal_gravity() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    for lat in [-90., -60., -12.5, 0., 33., 45., 80., 90.] {
+        let gamma = maprs3d::normal_gravity(lat, 0., &ell, true).unwrap();
+        let reference = maprs3d::somigliana(lat, &ell, true).unwrap();
+        assert!(maprs3d::is_close(gamma, reference, 1e-12, 0.));
+
+        let gamma_h = maprs3d::normal_gravity(lat, 1000., &ell, true).unwrap();
+        assert!(maprs3d::is_close(gamma - gamma_h, 3.086e-3, 5e-3, 0.));
+    }
+
+    let u0 = maprs3d::normal_potential(37., 0., &ell, true).unwrap();
+    assert!(maprs3d::is_close(u0, 62636851.7146, 1e-11, 0.));
+
+    let u = maprs3d::normal_potential(37., 100., &ell, true).unwrap();
+    assert!(maprs3d::is_close(u0 - u, 100. * 9.8, 2e-3, 0.));
+}
+
+#[test]
+fn test_gravity_vector() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    for (lat, lon) in [(0., 0.), (45., 30.), (-70., -120.)] {
+        let gamma = maprs3d::normal_gravity(lat, 0., &ell, true).unwrap();
+        let (e, n, u) = maprs3d::gravity_enu(lat, lon, 0., &ell, true).unwrap();
+
+        assert!(e.abs() < 1e-12);
+        assert!(n.abs() < 1e-9);
+        assert!(maprs3d::is_close(u, -gamma, 1e-12, 0.));
+
+        let (n2, e2, d) = maprs3d::gravity_ned(lat, lon, 0., &ell, true).unwrap();
+        assert_eq!((n2, e2, d), (n, e, -u));
+    }
+
+    let (x, y, z) = maprs3d::geodetic2ecef(45., 90., 10000., &ell, true).unwrap();
+    let (gx, gy, gz) = maprs3d::gravity_ecef(x, y, z, &ell).unwrap();
+    assert!(gx.abs() < 1e-12);
+    assert!(gy < 0. && gz < 0.);
+
+    let (_, n, _) = maprs3d::gravity_enu(45., 90., 10000., &ell, true).unwrap();
+    let lat = 45_f64.to_radians();
+    let dlat = 1e-6;
+    let m = maprs3d::meridian(lat, &ell, false).unwrap();
+    let u1 = maprs3d::normal_potential(lat + dlat, 10000., &ell, false).unwrap();
+    let u2 = maprs3d::normal_potential(lat - dlat, 10000., &ell, false).unwrap();
+    let reference = (u1 - u2) / (2. * dlat * (m + 10000.));
+    assert!(maprs3d::is_close(n, reference, 1e-3, 0.));
+}
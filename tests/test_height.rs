@@ -0,0 +1,107 @@
+use maprs3d::{Height, HeightKind, VerticalDatum};
+
+fn constant_geoid(n: f32) -> maprs3d::GeoidGrid {
+    maprs3d::GeoidGrid::new(-90., 0., 90., 90., 3, 5, vec![n; 15]).unwrap()
+}
+
+#[test]
+fn test_kinds() {
+    let h1 = Height::Orthometric(120.);
+    let h2 = Height::Orthometric(20.);
+    let h3 = Height::Ellipsoidal(20.);
+
+    assert_eq!(h1.kind(), HeightKind::Orthometric);
+    assert_eq!(h1.value(), 120.);
+    assert_eq!(h1.difference(&h2), Some(100.));
+    assert_eq!(h1.checked_add(&h2), Some(Height::Orthometric(140.)));
+    assert!(h1.difference(&h3).is_none());
+    assert!(h1.checked_add(&h3).is_none());
+    assert_eq!(Height::new(5., HeightKind::Normal), Height::Normal(5.));
+}
+
+#[test]
+fn test_geopotential() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    let c = maprs3d::normal2geopotential(1000., 45., &ell, true).unwrap();
+    assert!(maprs3d::is_close(c, 9806.2 - 1.5, 0., 1.));
+    let h = maprs3d::geopotential2normal(c, 45., &ell, true).unwrap();
+    assert!(maprs3d::is_close(h, 1000., 0., 1e-9));
+
+    let c = maprs3d::orthometric2geopotential(2000., 9.79);
+    let h = maprs3d::geopotential2orthometric(c, 9.79);
+    assert!(maprs3d::is_close(h, 2000., 0., 1e-9));
+
+    assert!(maprs3d::normal2geopotential(1000., 45., &maprs3d::Ellipsoid::airy(), true).is_none());
+}
+
+#[test]
+fn test_datum() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let geoid = constant_geoid(30.);
+    let quasigeoid = constant_geoid(29.5);
+
+    let datum = VerticalDatum::new(&ell)
+        .with_geoid(&geoid)
+        .with_quasigeoid(&quasigeoid);
+
+    let h = Height::Orthometric(1000.);
+    assert_eq!(datum.ellipsoidal(h, 42., -82., true), Some(1030.));
+
+    let normal = datum
+        .convert(h, HeightKind::Normal, 42., -82., true)
+        .unwrap();
+    assert_eq!(normal, Height::Normal(1000.5));
+
+    let c = datum
+        .convert(h, HeightKind::Geopotential, 42., -82., true)
+        .unwrap();
+    let back = datum
+        .convert(c, HeightKind::Orthometric, 42., -82., true)
+        .unwrap();
+    assert!(maprs3d::is_close(back.value(), 1000., 0., 1e-9));
+    assert_eq!(back.kind(), HeightKind::Orthometric);
+
+    let datum = VerticalDatum::new(&ell).with_geoid(&geoid);
+    assert!(datum
+        .convert(h, HeightKind::Normal, 42., -82., true)
+        .is_none());
+    assert!(datum
+        .ellipsoidal(Height::Normal(1.), 42., -82., true)
+        .is_none());
+}
+
+#[test]
+fn test_ecef() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let geoid = constant_geoid(30.);
+    let datum = VerticalDatum::new(&ell).with_geoid(&geoid);
+
+    let xyz =
+        maprs3d::geodetic2ecef_height(42., -82., Height::Orthometric(170.), &datum, true).unwrap();
+    assert_eq!(
+        xyz,
+        maprs3d::geodetic2ecef(42., -82., 200., &ell, true).unwrap()
+    );
+
+    let (lat, lon, h) =
+        maprs3d::ecef2geodetic_height(xyz.0, xyz.1, xyz.2, HeightKind::Orthometric, &datum, true)
+            .unwrap();
+    assert!(maprs3d::is_close(lat, 42., 1e-9, 0.));
+    assert!(maprs3d::is_close(lon, -82., 1e-9, 0.));
+    assert!(maprs3d::is_close(h.value(), 170., 0., 1e-6));
+    assert_eq!(h.kind(), HeightKind::Orthometric);
+
+    let enu = maprs3d::geodetic2enu_height(
+        42.,
+        -82.,
+        Height::Orthometric(170.),
+        42.,
+        -82.,
+        Height::Ellipsoidal(100.),
+        &datum,
+        true,
+    )
+    .unwrap();
+    assert!(maprs3d::is_close(enu.2, 100., 0., 1e-6));
+}
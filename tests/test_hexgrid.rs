@@ -0,0 +1,188 @@
+use maprs3d::{geodetic2authalic, sph2cart, HexGrid};
+
+fn spherical_area(boundary: &[(f64, f64)], ell: &maprs3d::Ellipsoid) -> f64 {
+    let points: Vec<(f64, f64, f64)> = boundary
+        .iter()
+        .map(|&(lat, lon)| {
+            let beta = geodetic2authalic(lat, ell, true).unwrap();
+            sph2cart(lon.to_radians(), beta.to_radians(), 1.)
+        })
+        .collect();
+
+    let a = points[0];
+    let mut area = 0.;
+
+    for k in 1..points.len() - 1 {
+        let (b, c) = (points[k], points[k + 1]);
+
+        let triple = a.0 * (b.1 * c.2 - b.2 * c.1) - a.1 * (b.0 * c.2 - b.2 * c.0)
+            + a.2 * (b.0 * c.1 - b.1 * c.0);
+        let d = 1.
+            + (a.0 * b.0 + a.1 * b.1 + a.2 * b.2)
+            + (b.0 * c.0 + b.1 * c.1 + b.2 * c.2)
+            + (c.0 * a.0 + c.1 * a.1 + c.2 * a.2);
+
+        area += 2. * triple.atan2(d);
+    }
+
+    area * maprs3d::authalic(ell).powi(2)
+}
+
+#[test]
+fn test_indexing() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    for aperture in [3, 7] {
+        let grid = HexGrid::new(aperture, &ell).unwrap();
+
+        for &(lat, lon) in &[(38.89, -77.03), (-33.87, 151.21), (0., 0.), (71.3, -156.8)] {
+            for resolution in [0, 1, 4, 9] {
+                let cell = grid.cell(lat, lon, resolution, true).unwrap();
+                assert_eq!(cell.resolution, resolution);
+
+                let (clat, clon) = grid.centre(&cell, true).unwrap();
+                assert_eq!(grid.cell(clat, clon, resolution, true).unwrap(), cell);
+
+                let radians = grid
+                    .cell(lat.to_radians(), lon.to_radians(), resolution, false)
+                    .unwrap();
+                assert_eq!(radians, cell);
+            }
+        }
+
+        let pole = grid.cell(90., 0., 5, true).unwrap();
+        assert!(grid.is_pentagon(&pole));
+        assert_eq!(grid.boundary(&pole, true).unwrap().len(), 5);
+
+        let (lat, _) = grid.centre(&pole, true).unwrap();
+        assert!(maprs3d::is_close(lat, 90., 0., 1e-9));
+
+        let cell = grid.cell(38.89, -77.03, 5, true).unwrap();
+        assert!(!grid.is_pentagon(&cell));
+        assert_eq!(grid.boundary(&cell, true).unwrap().len(), 6);
+
+        let max = grid.max_resolution();
+        assert!(grid.cell(0., 0., max, true).is_some());
+        assert!(grid.cell(0., 0., max + 1, true).is_none());
+        assert!(grid.cell(91., 0., 3, true).is_none());
+    }
+
+    assert!(HexGrid::new(4, &ell).is_none());
+}
+
+#[test]
+fn test_global() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    for (aperture, resolution) in [(3, 0), (3, 1), (3, 3), (7, 1), (7, 2)] {
+        let grid = HexGrid::new(aperture, &ell).unwrap();
+
+        let cell = grid.cell(10., 20., resolution, true).unwrap();
+        let cells = grid.k_ring(&cell, 100).unwrap();
+
+        assert_eq!(cells.len() as u64, grid.cell_count(resolution));
+        assert_eq!(cells.iter().filter(|c| grid.is_pentagon(c)).count(), 12);
+
+        for c in &cells {
+            let neighbours = grid.neighbours(c).unwrap();
+            assert_eq!(neighbours.len(), grid.boundary(c, true).unwrap().len());
+
+            for n in &neighbours {
+                assert!(grid.neighbours(n).unwrap().contains(c));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_k_ring() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let grid = HexGrid::new(7, &ell).unwrap();
+
+    let cell = grid.cell(48.8566, 2.3522, 8, true).unwrap();
+
+    for k in 0..4 {
+        let ring = grid.k_ring(&cell, k).unwrap();
+        assert_eq!(ring.len() as u32, 1 + 3 * k * (k + 1));
+        assert!(ring.contains(&cell));
+    }
+
+    let neighbours = grid.neighbours(&cell).unwrap();
+    assert_eq!(neighbours.len(), 6);
+    assert!(neighbours.iter().all(|n| n.resolution == 8 && *n != cell));
+
+    let pentagon = grid.cell(-90., 0., 8, true).unwrap();
+    assert_eq!(grid.neighbours(&pentagon).unwrap().len(), 5);
+    assert_eq!(grid.k_ring(&pentagon, 1).unwrap().len(), 6);
+    assert_eq!(grid.k_ring(&pentagon, 2).unwrap().len(), 16);
+
+    let mut invalid = cell;
+    invalid.face = 25;
+    assert!(grid.neighbours(&invalid).is_none());
+    assert!(grid.centre(&invalid, true).is_none());
+}
+
+#[test]
+fn test_hierarchy() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    let grid = HexGrid::new(7, &ell).unwrap();
+    let cell = grid.cell(-33.87, 151.21, 6, true).unwrap();
+
+    let children = grid.children(&cell).unwrap();
+    assert_eq!(children.len(), 7);
+    assert!(children.iter().all(|c| grid.parent(c) == Some(cell)));
+
+    let (lat, lon) = grid.centre(&cell, true).unwrap();
+    assert!(children.contains(&grid.cell(lat, lon, 7, true).unwrap()));
+
+    let pentagon = grid.cell(90., 0., 6, true).unwrap();
+    assert_eq!(grid.children(&pentagon).unwrap().len(), 6);
+
+    let root = grid.cell(0., 0., 0, true).unwrap();
+    assert!(grid.parent(&root).is_none());
+
+    let leaf = grid.cell(0., 0., grid.max_resolution(), true).unwrap();
+    assert!(grid.children(&leaf).is_none());
+
+    let grid = HexGrid::new(3, &ell).unwrap();
+
+    for resolution in [3, 4, 9, 10] {
+        let cell = grid.cell(38.89, -77.03, resolution, true).unwrap();
+        let children = grid.children(&cell).unwrap();
+
+        assert!((1..=7).contains(&children.len()));
+        assert!(children.iter().all(|c| grid.parent(c) == Some(cell)));
+
+        let (lat, lon) = grid.centre(&cell, true).unwrap();
+        assert!(children.contains(&grid.cell(lat, lon, resolution + 1, true).unwrap()));
+    }
+
+    let cell = grid.cell(38.89, -77.03, 6, true).unwrap();
+    let total: usize = grid
+        .k_ring(&cell, 3)
+        .unwrap()
+        .iter()
+        .map(|c| grid.children(c).unwrap().len())
+        .sum();
+    assert!((total as f64 / 37. - 3.).abs() < 0.5);
+}
+
+#[test]
+fn test_area() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    for aperture in [3, 7] {
+        let grid = HexGrid::new(aperture, &ell).unwrap();
+
+        let surface = grid.cell_area(4) * 10. * (aperture as f64).powi(4);
+        assert!(maprs3d::is_close(surface, 5.10065622e14, 1e-8, 0.));
+
+        for &(lat, lon) in &[(10., 20.), (-45., 100.), (50., 5.)] {
+            let cell = grid.cell(lat, lon, 10, true).unwrap();
+            let area = spherical_area(&grid.boundary(&cell, true).unwrap(), &ell);
+
+            assert!(maprs3d::is_close(area, grid.cell_area(10), 1e-4, 0.));
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use maprs3d::{conformal2geodetic, geodetic2conformal, geodetic2isometric, isometric2geodetic};
+
+#[test]
+fn test_conformal_roundtrip() {
+    for ell in [
+        maprs3d::Ellipsoid::wgs84(),
+        maprs3d::Ellipsoid::clarke1866(),
+        maprs3d::Ellipsoid::airy(),
+    ] {
+        for k in -90..=90 {
+            let lat = (k as f64).to_radians();
+
+            let conformal_lat = geodetic2conformal(lat, &ell, false).unwrap();
+            let lat1 = conformal2geodetic(conformal_lat, &ell, false).unwrap();
+            assert!(maprs3d::is_close(lat1, lat, 0., 1e-12), "{}", k);
+
+            let conformal_deg = geodetic2conformal(k as f64, &ell, true).unwrap();
+            let lat_deg = conformal2geodetic(conformal_deg, &ell, true).unwrap();
+            assert!(maprs3d::is_close(lat_deg, k as f64, 0., 1e-12), "{}", k);
+        }
+    }
+
+    let ell = maprs3d::Ellipsoid::wgs84();
+    assert!(conformal2geodetic(91., &ell, true).is_none());
+    assert!(geodetic2conformal(-91., &ell, true).is_none());
+}
+
+#[test]
+fn test_isometric_unbounded() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    for psi in [-10., -3., -1.6, 1.6, 3., 10.] {
+        let lat = isometric2geodetic(psi, &ell, false).unwrap();
+        assert!(lat.abs() < std::f64::consts::FRAC_PI_2);
+
+        let psi1 = geodetic2isometric(lat, &ell, false).unwrap();
+        assert!(maprs3d::is_close(psi1, psi, 1e-9, 0.), "{}", psi);
+    }
+
+    let lat = isometric2geodetic(f64::INFINITY, &ell, false).unwrap();
+    assert!(maprs3d::is_close(
+        lat,
+        std::f64::consts::FRAC_PI_2,
+        0.,
+        1e-12
+    ));
+
+    assert!(isometric2geodetic(f64::NAN, &ell, false).is_none());
+}
@@ -0,0 +1,95 @@
+use maprs3d::LambertConformalConic;
+
+#[test]
+fn test_1sp() {
+    let ell = maprs3d::Ellipsoid::clarke1866();
+    let lcc = LambertConformalConic::new_1sp(18., -77., 1., 250000., 150000., &ell, true).unwrap();
+
+    let lat = 17. + 55. / 60. + 55.80 / 3600.;
+    let lon = -(76. + 56. / 60. + 37.26 / 3600.);
+
+    let (x, y) = lcc.forward(lat, lon, true).unwrap();
+    assert!(maprs3d::is_close(x, 255966.58, 0., 1e-2));
+    assert!(maprs3d::is_close(y, 142493.51, 0., 1e-2));
+
+    let (lat1, lon1) = lcc.inverse(x, y, true).unwrap();
+    assert!(maprs3d::is_close(lat1, lat, 0., 1e-9));
+    assert!(maprs3d::is_close(lon1, lon, 0., 1e-10));
+
+    assert!(maprs3d::is_close(
+        lcc.scale(18., 0., true).unwrap(),
+        1.,
+        0.,
+        1e-12
+    ));
+    assert!(lcc.scale(30., 0., true).unwrap() > 1.);
+    assert!(maprs3d::is_close(
+        lcc.convergence(18., -76., true).unwrap(),
+        18f64.to_radians().sin(),
+        0.,
+        1e-12
+    ));
+}
+
+#[test]
+fn test_2sp() {
+    let ell = maprs3d::Ellipsoid::clarke1866();
+    let ft = 1200. / 3937.;
+    let lcc = LambertConformalConic::new_2sp(
+        28. + 23. / 60.,
+        30. + 17. / 60.,
+        27. + 50. / 60.,
+        -99.,
+        2000000. * ft,
+        0.,
+        &ell,
+        true,
+    )
+    .unwrap();
+
+    let (x, y) = lcc.forward(28.5, -96., true).unwrap();
+    assert!(maprs3d::is_close(x / ft, 2963503.91, 0., 1e-2));
+    assert!(maprs3d::is_close(y / ft, 254759.80, 0., 1e-2));
+
+    let (lat, lon) = lcc.inverse(x, y, true).unwrap();
+    assert!(maprs3d::is_close(lat, 28.5, 0., 1e-9));
+    assert!(maprs3d::is_close(lon, -96., 0., 1e-10));
+
+    assert!(maprs3d::is_close(
+        lcc.scale(28. + 23. / 60., -96., true).unwrap(),
+        1.,
+        0.,
+        1e-12
+    ));
+    assert!(maprs3d::is_close(
+        lcc.scale(30. + 17. / 60., -99., true).unwrap(),
+        1.,
+        0.,
+        1e-12
+    ));
+    assert!(lcc.scale(29., -99., true).unwrap() < 1.);
+}
+
+#[test]
+fn test_roundtrip() {
+    let ell = maprs3d::Ellipsoid::grs80();
+    let lcc =
+        LambertConformalConic::new_2sp(35., 65., 52., 10., 4000000., 2800000., &ell, true).unwrap();
+
+    for &(lat, lon) in &[(52., 10.), (70., -20.), (30., 40.), (89., 170.), (-10., 0.)] {
+        let (x, y) = lcc.forward(lat, lon, true).unwrap();
+        let (lat1, lon1) = lcc.inverse(x, y, true).unwrap();
+        assert!(maprs3d::is_close(lat1, lat, 0., 1e-9));
+        assert!(maprs3d::is_close(lon1, lon, 0., 1e-10));
+    }
+
+    assert!(lcc.forward(-90., 0., true).is_none());
+
+    let south = LambertConformalConic::new_2sp(-30., -50., -40., 140., 0., 0., &ell, true).unwrap();
+    let (x, y) = south.forward(-45., 150., true).unwrap();
+    let (lat, lon) = south.inverse(x, y, true).unwrap();
+    assert!(maprs3d::is_close(lat, -45., 0., 1e-9));
+    assert!(maprs3d::is_close(lon, 150., 0., 1e-10));
+
+    assert!(LambertConformalConic::new_1sp(0., 0., 1., 0., 0., &ell, true).is_none());
+}
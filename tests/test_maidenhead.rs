@@ -0,0 +1,95 @@
+use maprs3d::{geodetic2maidenhead, maidenhead2geodetic, maidenhead_bbox, maidenhead_distance};
+
+#[test]
+fn test_encode() {
+    assert_eq!(
+        geodetic2maidenhead(48.146, 11.608, 6, true).unwrap(),
+        "JN58td"
+    );
+    assert_eq!(
+        geodetic2maidenhead(41.714775, -72.727260, 6, true).unwrap(),
+        "FN31pr"
+    );
+    assert_eq!(
+        geodetic2maidenhead(41.714775, -72.727260, 2, true).unwrap(),
+        "FN"
+    );
+    assert_eq!(
+        geodetic2maidenhead(41.714775, -72.727260, 4, true).unwrap(),
+        "FN31"
+    );
+    assert_eq!(
+        geodetic2maidenhead(-90., -180., 10, true).unwrap(),
+        "AA00aa00aa"
+    );
+    assert_eq!(
+        geodetic2maidenhead(90., 179.99999, 10, true).unwrap(),
+        "RR99xx99xx"
+    );
+
+    let locator = geodetic2maidenhead(-33.8688, 151.2093, 10, true).unwrap();
+    assert_eq!(locator.len(), 10);
+    assert!(locator.starts_with("QF56od"));
+
+    assert!(geodetic2maidenhead(91., 0., 6, true).is_none());
+    assert!(geodetic2maidenhead(0., 0., 5, true).is_none());
+    assert!(geodetic2maidenhead(0., 0., 12, true).is_none());
+}
+
+#[test]
+fn test_decode() {
+    let (south, west, north, east) = maidenhead_bbox("JN58td", true).unwrap();
+    assert!(maprs3d::is_close(south, 48. + 7.5 / 60., 0., 1e-12));
+    assert!(maprs3d::is_close(west, 11. + 35. / 60., 0., 1e-12));
+    assert!(maprs3d::is_close(north, 48. + 10. / 60., 0., 1e-12));
+    assert!(maprs3d::is_close(east, 11. + 40. / 60., 0., 1e-12));
+
+    let (lat, lon) = maidenhead2geodetic("jn58TD", true).unwrap();
+    assert!(maprs3d::is_close(lat, 48. + 8.75 / 60., 0., 1e-12));
+    assert!(maprs3d::is_close(lon, 11.625, 0., 1e-12));
+
+    for length in [2, 4, 6, 8, 10] {
+        let locator = geodetic2maidenhead(-22.9068, -43.1729, length, true).unwrap();
+        let (south, west, north, east) = maidenhead_bbox(&locator, true).unwrap();
+        assert!(south <= -22.9068 && -22.9068 < north);
+        assert!(west <= -43.1729 && -43.1729 < east);
+
+        let (lat, lon) = maidenhead2geodetic(&locator, true).unwrap();
+        assert_eq!(
+            geodetic2maidenhead(lat, lon, length, true).unwrap(),
+            locator
+        );
+    }
+
+    let (south, west, north, east) = maidenhead_bbox("JN58td25xx", true).unwrap();
+    assert!(maprs3d::is_close(north - south, 0.625 / 3600., 0., 1e-12));
+    assert!(maprs3d::is_close(east - west, 1.25 / 3600., 0., 1e-12));
+
+    assert!(maidenhead_bbox("SA", true).is_none());
+    assert!(maidenhead_bbox("JN5", true).is_none());
+    assert!(maidenhead_bbox("JN58yd", true).is_none());
+    assert!(maidenhead_bbox("JNa8", true).is_none());
+    assert!(maidenhead_bbox("ŁN58", true).is_none());
+    assert!(maidenhead_bbox("JN5ĸ", true).is_none());
+    assert!(maidenhead_bbox("JN58ţd", true).is_none());
+}
+
+#[test]
+fn test_distance() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    let (distance, azimuth) = maidenhead_distance("JN58td", "FN31pr", &ell, true).unwrap();
+    let (lat1, lon1) = maidenhead2geodetic("JN58td", true).unwrap();
+    let (lat2, lon2) = maidenhead2geodetic("FN31pr", true).unwrap();
+    let (s, az) = maprs3d::vdist(lat1, lon1, lat2, lon2, &ell, true).unwrap();
+
+    assert!(maprs3d::is_close(distance, s, 0., 1e-6));
+    assert!(maprs3d::is_close(azimuth, az, 0., 1e-9));
+    assert!(distance > 6.2e6 && distance < 6.6e6);
+    assert!(azimuth > 290. && azimuth < 310.);
+
+    let (distance, _) = maidenhead_distance("JN58td", "JN58td", &ell, true).unwrap();
+    assert_eq!(distance, 0.);
+
+    assert!(maidenhead_distance("JN58td", "ZZ", &ell, true).is_none());
+}
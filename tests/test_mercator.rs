@@ -0,0 +1,108 @@
+use maprs3d::Mercator;
+
+#[test]
+fn test_mercator() {
+    let ell = maprs3d::Ellipsoid::bessel();
+    let merc = Mercator::new(110., 0.997, 3900000., 900000., &ell, true).unwrap();
+
+    let (x, y) = merc.forward(-3., 120., true).unwrap();
+    assert!(maprs3d::is_close(x, 5009726.58, 0., 1e-2));
+    assert!(maprs3d::is_close(y, 569150.82, 0., 1e-2));
+
+    let (lat, lon) = merc.inverse(x, y, true).unwrap();
+    assert!(maprs3d::is_close(lat, -3., 0., 1e-9));
+    assert!(maprs3d::is_close(lon, 120., 0., 1e-10));
+
+    let ell = maprs3d::Ellipsoid::krassovsky1940();
+    let merc = Mercator::new_2sp(42., 51., 0., 0., &ell, true).unwrap();
+
+    let (x, y) = merc.forward(53., 53., true).unwrap();
+    assert!(maprs3d::is_close(x, 165704.29, 0., 1e-2));
+    assert!(maprs3d::is_close(y, 5171848.07, 0., 1e-2));
+
+    let (lat, lon) = merc.inverse(x, y, true).unwrap();
+    assert!(maprs3d::is_close(lat, 53., 0., 1e-9));
+    assert!(maprs3d::is_close(lon, 53., 0., 1e-10));
+
+    assert!(maprs3d::is_close(
+        merc.scale(42., 0., true).unwrap(),
+        1.,
+        0.,
+        1e-12
+    ));
+    assert_eq!(merc.convergence(42., 10., true), Some(0.));
+    assert!(merc.forward(90., 0., true).is_none());
+}
+
+#[test]
+fn test_web_mercator() {
+    let web = Mercator::web();
+
+    let lat = 24. + 22. / 60. + 54.433 / 3600.;
+    let (x, y) = web.forward(lat, -100. - 20. / 60., true).unwrap();
+    assert!(maprs3d::is_close(x, -11169055.58, 0., 1e-2));
+    assert!(maprs3d::is_close(y, 2800000.00, 0., 1e-2));
+
+    let (lat1, lon1) = web.inverse(x, y, true).unwrap();
+    assert!(maprs3d::is_close(lat1, lat, 0., 1e-12));
+    assert!(maprs3d::is_close(lon1, -100. - 20. / 60., 0., 1e-12));
+
+    let (_, y) = web.forward(maprs3d::tile::MAX_LATITUDE, 0., true).unwrap();
+    assert!(maprs3d::is_close(y, 20037508.342789244, 0., 1e-6));
+}
+
+#[test]
+fn test_tiles() {
+    assert_eq!(maprs3d::geodetic2tile(0., 0., 0, true), Some((0, 0)));
+    assert_eq!(maprs3d::geodetic2tile(0., 0., 1, true), Some((1, 1)));
+    assert_eq!(
+        maprs3d::geodetic2tile(51.5, -0.12, 10, true),
+        Some((511, 340))
+    );
+    assert_eq!(maprs3d::geodetic2tile(89., 180., 3, true), Some((7, 0)));
+    assert_eq!(maprs3d::geodetic2tile(-89., -180., 3, true), Some((0, 7)));
+    assert!(maprs3d::geodetic2tile(0., 0., 31, true).is_none());
+
+    let (px, py) = maprs3d::geodetic2pixel(51.5, -0.12, 10, 256, true).unwrap();
+    assert_eq!(((px / 256.) as u32, (py / 256.) as u32), (511, 340));
+    let (lat, lon) = maprs3d::pixel2geodetic(px, py, 10, 256, true).unwrap();
+    assert!(maprs3d::is_close(lat, 51.5, 0., 1e-12));
+    assert!(maprs3d::is_close(lon, -0.12, 0., 1e-12));
+
+    let (lat, lon) = maprs3d::tile2geodetic(0, 0, 0, true).unwrap();
+    assert!(maprs3d::is_close(
+        lat,
+        maprs3d::tile::MAX_LATITUDE,
+        0.,
+        1e-12
+    ));
+    assert_eq!(lon, -180.);
+
+    let (south, west, north, east) = maprs3d::tile_bbox(1, 1, 1, true).unwrap();
+    assert!(maprs3d::is_close(
+        south,
+        -maprs3d::tile::MAX_LATITUDE,
+        0.,
+        1e-12
+    ));
+    assert!(maprs3d::is_close(north, 0., 0., 1e-12));
+    assert_eq!((west, east), (0., 180.));
+
+    let (south, west, north, east) = maprs3d::tile_bbox(511, 340, 10, true).unwrap();
+    assert!(south < 51.5 && 51.5 < north);
+    assert!(west < -0.12 && -0.12 < east);
+    assert!(maprs3d::tile_bbox(2, 0, 1, true).is_none());
+}
+
+#[test]
+fn test_quadkey() {
+    assert_eq!(maprs3d::tile2quadkey(3, 5, 3), Some("213".to_string()));
+    assert_eq!(maprs3d::tile2quadkey(0, 0, 0), Some(String::new()));
+    assert_eq!(maprs3d::quadkey2tile("213"), Some((3, 5, 3)));
+    assert_eq!(maprs3d::quadkey2tile(""), Some((0, 0, 0)));
+    assert!(maprs3d::quadkey2tile("124").is_none());
+    assert!(maprs3d::tile2quadkey(8, 0, 3).is_none());
+
+    let quadkey = maprs3d::tile2quadkey(511, 340, 10).unwrap();
+    assert_eq!(maprs3d::quadkey2tile(&quadkey), Some((511, 340, 10)));
+}
@@ -0,0 +1,129 @@
+use maprs3d::Mgrs;
+
+#[test]
+fn test_parse() {
+    let mgrs = Mgrs::parse(" 31u dq 48265 11920 ").unwrap();
+    assert_eq!(mgrs.zone, 31);
+    assert_eq!(mgrs.band, 'U');
+    assert_eq!((mgrs.column, mgrs.row), ('D', 'Q'));
+    assert_eq!((mgrs.easting, mgrs.northing), (48265, 11920));
+    assert_eq!(mgrs.precision, 5);
+    assert_eq!(mgrs.to_string(), "31UDQ4826511920");
+    assert_eq!(mgrs.usng(), "31U DQ 48265 11920");
+
+    let mgrs = Mgrs::parse("4QFJ1234567890").unwrap();
+    assert_eq!(mgrs.zone, 4);
+    assert_eq!(mgrs.to_string(), "4QFJ1234567890");
+
+    let mgrs = Mgrs::parse("04QFJ").unwrap();
+    assert_eq!(mgrs.precision, 0);
+    assert_eq!(mgrs.usng(), "4Q FJ");
+
+    let mgrs = Mgrs::parse("4QFJ123678").unwrap();
+    assert_eq!((mgrs.easting, mgrs.northing), (12300, 67800));
+    assert_eq!(mgrs.cell_size(), 100.);
+
+    assert!(Mgrs::parse("ZAH0000000000").is_some());
+    assert!(Mgrs::parse("61QFJ12").is_none());
+    assert!(Mgrs::parse("4IFJ12").is_none());
+    assert!(Mgrs::parse("4QFJ123").is_none());
+    assert!(Mgrs::parse("4QFO12").is_none());
+    assert!(Mgrs::parse("QFJ12").is_none());
+    assert!(Mgrs::parse("4QFJ12345678901").is_none());
+    assert!(Mgrs::parse("").is_none());
+}
+
+#[test]
+fn test_utm() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    let mgrs = maprs3d::geodetic2mgrs(48.858093, 2.294694, 5, &ell, true).unwrap();
+    assert_eq!(mgrs.to_string(), "31UDQ4826511920");
+
+    let mgrs = maprs3d::geodetic2mgrs(48.858093, 2.294694, 2, &ell, true).unwrap();
+    assert_eq!(mgrs.to_string(), "31UDQ4811");
+
+    let mgrs = maprs3d::geodetic2mgrs(38.8895, -77.0353, 5, &ell, true).unwrap();
+    assert_eq!(mgrs.usng(), "18S UJ 23478 06483");
+
+    let mgrs = maprs3d::geodetic2mgrs(-33.857, 151.215, 5, &ell, true).unwrap();
+    assert_eq!(mgrs.to_string(), "56HLH3487352266");
+
+    for &(lat, lon) in &[
+        (48.858093, 2.294694),
+        (-33.857, 151.215),
+        (0.1, -0.1),
+        (-79.9, 100.),
+        (83.9, 10.),
+        (63., 5.),
+        (-0.5, 179.9),
+    ] {
+        let mgrs = maprs3d::geodetic2mgrs(lat, lon, 5, &ell, true).unwrap();
+        let parsed = Mgrs::parse(&mgrs.to_string()).unwrap();
+        assert_eq!(parsed, mgrs);
+        let (lat1, lon1) = maprs3d::mgrs2geodetic(&parsed, &ell, true).unwrap();
+        assert!(maprs3d::is_close(lat1, lat, 0., 2e-5));
+        assert!(maprs3d::is_close(lon1, lon, 0., 1e-4));
+    }
+}
+
+#[test]
+fn test_old_lettering() {
+    let wgs84 = maprs3d::Ellipsoid::wgs84();
+    let bessel = maprs3d::Ellipsoid::bessel();
+    let rows = "ABCDEFGHJKLMNPQRSTUV";
+
+    for &(lat, lon) in &[(52., 13.), (52., 10.), (-20., 25.)] {
+        let new = maprs3d::geodetic2mgrs(lat, lon, 0, &wgs84, true).unwrap();
+        let old = maprs3d::geodetic2mgrs(lat, lon, 0, &bessel, true).unwrap();
+
+        let i = rows.find(new.row).unwrap();
+        let j = rows.find(old.row).unwrap();
+        assert_eq!((j + 20 - i) % 20, 10);
+
+        let (lat1, lon1) = maprs3d::mgrs2geodetic(&old, &bessel, true).unwrap();
+        assert!(maprs3d::is_close(lat1, lat, 0., 1.));
+        assert!(maprs3d::is_close(lon1, lon, 0., 1.5));
+    }
+
+    let clarke = maprs3d::Ellipsoid::clarke1866();
+    let mgrs = maprs3d::geodetic2mgrs(38.8895, -77.0353, 5, &clarke, true).unwrap();
+    let (lat, lon) = maprs3d::mgrs2geodetic(&mgrs, &clarke, true).unwrap();
+    assert!(maprs3d::is_close(lat, 38.8895, 0., 2e-5));
+    assert!(maprs3d::is_close(lon, -77.0353, 0., 2e-5));
+}
+
+#[test]
+fn test_ups() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    let mgrs = maprs3d::geodetic2mgrs(90., 0., 5, &ell, true).unwrap();
+    assert_eq!(mgrs.to_string(), "ZAH0000000000");
+
+    let mgrs = maprs3d::geodetic2mgrs(-90., 0., 5, &ell, true).unwrap();
+    assert_eq!(mgrs.to_string(), "BAN0000000000");
+
+    for &(lat, lon) in &[
+        (85., -30.),
+        (86., 120.),
+        (-81., -170.),
+        (-88., 45.),
+        (84.5, 0.),
+    ] {
+        let mgrs = maprs3d::geodetic2mgrs(lat, lon, 5, &ell, true).unwrap();
+        assert_eq!(mgrs.zone, 0);
+        let parsed = Mgrs::parse(&mgrs.to_string()).unwrap();
+        assert_eq!(parsed, mgrs);
+        let (lat1, lon1) = maprs3d::mgrs2geodetic(&parsed, &ell, true).unwrap();
+        assert!(maprs3d::is_close(lat1, lat, 0., 1e-5));
+        assert!(maprs3d::is_close(lon1, lon, 0., 1e-3));
+    }
+
+    let (zone, northern, easting, northing) =
+        maprs3d::mgrs2grid(&Mgrs::parse("ZAH").unwrap(), &ell).unwrap();
+    assert_eq!((zone, northern), (0, true));
+    assert_eq!((easting, northing), (2000000., 2000000.));
+
+    assert!(maprs3d::mgrs2grid(&Mgrs::parse("ZKH").unwrap(), &ell).is_none());
+    assert!(maprs3d::mgrs2grid(&Mgrs::parse("31UJQ").unwrap(), &ell).is_none());
+}
@@ -0,0 +1,137 @@
+use maprs3d::{
+    geodetic2olc, olc2geodetic, olc_bbox, olc_is_full, olc_is_short, olc_is_valid, olc_recover,
+    olc_shorten,
+};
+
+fn assert_bbox(code: &str, expected: (f64, f64, f64, f64)) {
+    let (south, west, north, east) = olc_bbox(code, true).unwrap();
+
+    assert!(maprs3d::is_close(south, expected.0, 0., 1e-10), "{}", code);
+    assert!(maprs3d::is_close(west, expected.1, 0., 1e-10), "{}", code);
+    assert!(maprs3d::is_close(north, expected.2, 0., 1e-10), "{}", code);
+    assert!(maprs3d::is_close(east, expected.3, 0., 1e-10), "{}", code);
+}
+
+#[test]
+fn test_validity() {
+    assert!(olc_is_valid("8FVC9G8F+6X"));
+    assert!(olc_is_full("8FVC9G8F+6X"));
+    assert!(!olc_is_short("8FVC9G8F+6X"));
+
+    assert!(olc_is_valid("9G8F+6X"));
+    assert!(olc_is_short("9G8F+6X"));
+    assert!(!olc_is_full("9G8F+6X"));
+
+    assert!(olc_is_valid("8FVC0000+"));
+    assert!(olc_is_full("8fvc0000+"));
+
+    assert!(!olc_is_valid("8FVC9G8F6X"));
+    assert!(!olc_is_valid("8FVC9G8F+6"));
+    assert!(!olc_is_valid("8FVC9G8F++6X"));
+    assert!(!olc_is_valid("8FVC9G8+F6X"));
+    assert!(!olc_is_valid("8FVC9G8F+6A"));
+    assert!(!olc_is_valid("80000000+"));
+    assert!(!olc_is_valid("8F0000+"));
+    assert!(!olc_is_valid("8FVC0000+6X"));
+    assert!(!olc_is_full("WC2345G6+"));
+}
+
+#[test]
+fn test_encode() {
+    assert_eq!(
+        geodetic2olc(47.3655625, 8.5249375, 10, true).unwrap(),
+        "8FVC9G8F+6X"
+    );
+    assert_eq!(geodetic2olc(20.375, 2.775, 6, true).unwrap(), "7FG49Q00+");
+    assert_eq!(geodetic2olc(20.375, 2.775, 2, true).unwrap(), "7F000000+");
+    assert_eq!(geodetic2olc(20.375, 2.775, 8, true).unwrap(), "7FG49QGG+");
+
+    let code = geodetic2olc(20.3701, 2.7822, 11, true).unwrap();
+    assert_eq!(code, "7FG49QCJ+2V".to_string() + &code[11..]);
+    assert_eq!(code.len(), 12);
+
+    assert_eq!(geodetic2olc(90., 1., 4, true).unwrap(), "CFX30000+");
+    assert_eq!(
+        geodetic2olc(0., 180., 10, true).unwrap(),
+        geodetic2olc(0., -180., 10, true).unwrap()
+    );
+
+    assert!(geodetic2olc(0., 0., 7, true).is_none());
+    assert!(geodetic2olc(0., 0., 16, true).is_none());
+    assert!(geodetic2olc(0., 0., 1, true).is_none());
+}
+
+#[test]
+fn test_decode() {
+    assert_bbox("8FVC9G8F+6X", (47.3655, 8.524875, 47.365625, 8.525));
+    assert_bbox("7FG49Q00+", (20.35, 2.75, 20.4, 2.8));
+    assert_bbox("7FG49QCJ+2V", (20.37, 2.782125, 20.370125, 2.78225));
+    assert_bbox("7fg49qcj+2vx", (20.3701, 2.78221875, 20.370125, 2.78225));
+
+    let (lat, lon) = olc2geodetic("8FVC9G8F+6X", true).unwrap();
+    assert!(maprs3d::is_close(lat, 47.3655625, 0., 1e-10));
+    assert!(maprs3d::is_close(lon, 8.5249375, 0., 1e-10));
+
+    for length in [2, 4, 6, 8, 10, 11, 12, 13, 14, 15] {
+        let code = geodetic2olc(-41.2865, 174.7762, length, true).unwrap();
+        let (south, west, north, east) = olc_bbox(&code, true).unwrap();
+        assert!(south <= -41.2865 && -41.2865 < north, "{}", code);
+        assert!(west <= 174.7762 && 174.7762 < east, "{}", code);
+
+        let (lat, lon) = olc2geodetic(&code, false).unwrap();
+        assert_eq!(geodetic2olc(lat, lon, length, false).unwrap(), code);
+    }
+
+    assert!(olc_bbox("9G8F+6X", true).is_none());
+    assert!(olc2geodetic("not a code", true).is_none());
+}
+
+#[test]
+fn test_short() {
+    assert_eq!(
+        olc_shorten("9C3W9QCJ+2VX", 51.3701125, -1.217765625, true).unwrap(),
+        "+2VX"
+    );
+    assert_eq!(
+        olc_shorten("9C3W9QCJ+2VX", 51.3708675, -1.217765625, true).unwrap(),
+        "CJ+2VX"
+    );
+    assert_eq!(
+        olc_shorten("8FVC9G8F+6X", 47.4, 8.6, true).unwrap(),
+        "9G8F+6X"
+    );
+    assert_eq!(
+        olc_shorten("8FVC9G8F+6X", 10., 8.6, true).unwrap(),
+        "8FVC9G8F+6X"
+    );
+
+    assert!(olc_shorten("8FVC0000+", 47.4, 8.6, true).is_none());
+    assert!(olc_shorten("8FV00000+", 47.4, 8.6, true).is_none());
+
+    assert_eq!(
+        olc_recover("+2VX", 51.3708675, -1.217765625, true).unwrap(),
+        "9C3W9QCJ+2VX"
+    );
+    assert_eq!(
+        olc_recover("9G8F+6X", 47.4, 8.6, true).unwrap(),
+        "8FVC9G8F+6X"
+    );
+    assert_eq!(
+        olc_recover("9g8f+6x", 47.4, 8.6, true).unwrap(),
+        "8FVC9G8F+6X"
+    );
+    assert_eq!(
+        olc_recover("8fvc9g8f+6x", 0., 0., true).unwrap(),
+        "8FVC9G8F+6X"
+    );
+
+    let full = geodetic2olc(47.0, 8.52, 10, true).unwrap();
+    let short = &full[4..];
+    assert_eq!(olc_recover(short, 46.98, 8.53, true).unwrap(), full);
+
+    let full = geodetic2olc(0.001, 179.999, 10, true).unwrap();
+    let short = &full[4..];
+    assert_eq!(olc_recover(short, 0.001, -179.99, true).unwrap(), full);
+
+    assert!(olc_recover("9G8F", 47.4, 8.6, true).is_none());
+}
@@ -0,0 +1,103 @@
+use maprs3d::HotineObliqueMercator;
+
+fn borneo(variant_b: bool) -> HotineObliqueMercator {
+    let ell = maprs3d::Ellipsoid::everest1967();
+    let azimuth = 53. + 18. / 60. + 56.9537 / 3600.;
+    let rectified = 53. + 7. / 60. + 48.3685 / 3600.;
+
+    if variant_b {
+        HotineObliqueMercator::variant_b(
+            4., 115., azimuth, rectified, 0.99984, 590476.87, 442857.65, &ell, true,
+        )
+        .unwrap()
+    } else {
+        HotineObliqueMercator::variant_a(
+            4., 115., azimuth, rectified, 0.99984, 590476.87, 442857.65, &ell, true,
+        )
+        .unwrap()
+    }
+}
+
+#[test]
+fn test_variant_b() {
+    let omerc = borneo(true);
+
+    let lat = 5. + 23. / 60. + 14.1129 / 3600.;
+    let lon = 115. + 48. / 60. + 19.8196 / 3600.;
+
+    let (x, y) = omerc.forward(lat, lon, true).unwrap();
+    assert!(maprs3d::is_close(x, 679245.73, 0., 1e-2));
+    assert!(maprs3d::is_close(y, 596562.78, 0., 1e-2));
+
+    let (lat1, lon1) = omerc.inverse(x, y, true).unwrap();
+    assert!(maprs3d::is_close(lat1, lat, 0., 1e-9));
+    assert!(maprs3d::is_close(lon1, lon, 0., 1e-10));
+
+    let (x, y) = omerc.forward(4., 115., true).unwrap();
+    assert!(maprs3d::is_close(x, 590476.87, 0., 1e-6));
+    assert!(maprs3d::is_close(y, 442857.65, 0., 1e-6));
+    assert!(maprs3d::is_close(
+        omerc.scale(4., 115., true).unwrap(),
+        0.99984,
+        0.,
+        1e-12
+    ));
+}
+
+#[test]
+fn test_variant_a() {
+    let a = borneo(false);
+    let b = borneo(true);
+
+    let (xa, ya) = a.forward(4., 115., true).unwrap();
+    let (xb, yb) = b.forward(4., 115., true).unwrap();
+
+    for &(lat, lon) in &[(5., 116.), (2., 112.), (7., 119.)] {
+        let (x1, y1) = a.forward(lat, lon, true).unwrap();
+        let (x2, y2) = b.forward(lat, lon, true).unwrap();
+        assert!(maprs3d::is_close(x1 - x2, xa - xb, 0., 1e-6));
+        assert!(maprs3d::is_close(y1 - y2, ya - yb, 0., 1e-6));
+
+        let (lat1, lon1) = a.inverse(x1, y1, true).unwrap();
+        assert!(maprs3d::is_close(lat1, lat, 0., 1e-9));
+        assert!(maprs3d::is_close(lon1, lon, 0., 1e-10));
+    }
+}
+
+#[test]
+fn test_alaska() {
+    let ell = maprs3d::Ellipsoid::grs80();
+    let azimuth = (-3f64 / 4.).atan().to_degrees();
+    let omerc = HotineObliqueMercator::variant_b(
+        57.,
+        -133.0 - 2. / 3.,
+        azimuth,
+        azimuth,
+        0.9999,
+        5000000.,
+        -5000000.,
+        &ell,
+        true,
+    )
+    .unwrap();
+
+    for &(lat, lon) in &[
+        (57., -133.66666666666666),
+        (58.3, -134.4),
+        (55.3, -131.6),
+        (60., -141.),
+    ] {
+        let (x, y) = omerc.forward(lat, lon, true).unwrap();
+        let (lat1, lon1) = omerc.inverse(x, y, true).unwrap();
+        assert!(maprs3d::is_close(lat1, lat, 0., 1e-9));
+        assert!(maprs3d::is_close(lon1, lon, 0., 1e-10));
+        assert!(maprs3d::is_close(
+            omerc.scale(lat, lon, true).unwrap(),
+            1.,
+            0.,
+            1e-3
+        ));
+    }
+
+    assert!(HotineObliqueMercator::variant_b(0., 0., 45., 45., 1., 0., 0., &ell, true).is_none());
+}
@@ -0,0 +1,165 @@
+use maprs3d::{geodetic2gridref, gridref2geodetic, GridRef, NationalGrid, Ostn15};
+
+#[test]
+fn test_british() {
+    let lat = 52. + 39. / 60. + 27.2531 / 3600.;
+    let lon = 1. + 43. / 60. + 4.5177 / 3600.;
+
+    let (e, n) = NationalGrid::British
+        .projection()
+        .forward(lat, lon, true)
+        .unwrap();
+    assert!(maprs3d::is_close(e, 651409.903, 0., 1e-3));
+    assert!(maprs3d::is_close(n, 313177.270, 0., 1e-3));
+
+    let gridref = geodetic2gridref(lat, lon, NationalGrid::British, 5, true).unwrap();
+    assert_eq!(gridref.to_string(), "TG 51409 13177");
+
+    let gridref = geodetic2gridref(lat, lon, NationalGrid::British, 3, true).unwrap();
+    assert_eq!(gridref.to_string(), "TG 514 131");
+    assert_eq!((gridref.easting, gridref.northing), (651400, 313100));
+
+    let gridref = GridRef::parse("TQ 30080 80125").unwrap();
+    assert_eq!(gridref.grid, NationalGrid::British);
+    assert_eq!((gridref.easting, gridref.northing), (530080, 180125));
+    assert_eq!(gridref.cell_size(), 1.);
+
+    let (lat, lon) = gridref2geodetic(&gridref, true).unwrap();
+    let back = geodetic2gridref(lat, lon, NationalGrid::British, 5, true).unwrap();
+    assert_eq!(back, gridref);
+
+    let gridref = GridRef::parse("sv").unwrap();
+    assert_eq!(
+        (gridref.easting, gridref.northing, gridref.precision),
+        (0, 0, 0)
+    );
+    assert_eq!(gridref.to_string(), "SV");
+
+    for (s, e, n) in [
+        ("HP 40000 10000", 440000, 1210000),
+        ("NN 1665 7128", 216650, 771280),
+        ("SW 4 2", 140000, 20000),
+    ] {
+        let gridref = GridRef::parse(s).unwrap();
+        assert_eq!((gridref.easting, gridref.northing), (e, n));
+        assert_eq!(gridref.to_string(), s);
+    }
+
+    assert!(GridRef::parse("TQ 3008 80125").is_none());
+    assert!(GridRef::parse("TI 30080 80125").is_none());
+    assert!(GridRef::parse("AA 00000 00000").is_none());
+    assert!(GridRef::parse("TQ1234567890123").is_none());
+    assert!(GridRef::new(NationalGrid::British, 700000., 0., 5).is_none());
+    assert!(geodetic2gridref(40., 0., NationalGrid::British, 5, true).is_none());
+}
+
+#[test]
+fn test_irish() {
+    let gridref = GridRef::parse("O 15904 34671").unwrap();
+    assert_eq!(gridref.grid, NationalGrid::Irish);
+    assert_eq!((gridref.easting, gridref.northing), (315904, 234671));
+    assert_eq!(gridref.to_string(), "O 15904 34671");
+
+    let (lat, lon) = gridref2geodetic(&gridref, true).unwrap();
+    assert!(lat > 53.3 && lat < 53.4);
+    assert!(lon > -6.3 && lon < -6.2);
+
+    let back = geodetic2gridref(lat, lon, NationalGrid::Irish, 5, true).unwrap();
+    assert_eq!(back, gridref);
+
+    assert_eq!(GridRef::parse("V").unwrap().easting, 0);
+    assert_eq!(GridRef::parse("E").unwrap().northing, 400000);
+    assert!(GridRef::parse("I 1 1").is_none());
+
+    assert_eq!(NationalGrid::Irish.ellipsoid().model, "airy_modified");
+}
+
+fn synthetic() -> String {
+    let mut csv = String::from(
+        "Point_ID,ETRS89_Easting,ETRS89_Northing,ETRS89_OSTN15_Shift_East,\
+         ETRS89_OSTN15_Shift_North,ETRS89_OSTN15_Geoid_Height,Datum_Flag\n",
+    );
+
+    let mut id = 1;
+    for row in 0..4 {
+        for col in 0..4 {
+            let e = 649000. + 1000. * col as f64;
+            let n = 311000. + 1000. * row as f64;
+            let flag = if row == 0 && col == 0 { 0 } else { 1 };
+
+            csv.push_str(&format!(
+                "{},{},{},{:.3},{:.3},{:.3},{}\n",
+                id,
+                e,
+                n,
+                102.5 + 0.25 * col as f64 + 0.125 * row as f64,
+                -78.25 - 0.5 * row as f64,
+                43.5 + 0.1 * col as f64,
+                flag
+            ));
+            id += 1;
+        }
+    }
+
+    csv
+}
+
+#[test]
+fn test_ostn15() {
+    let ostn = Ostn15::from_csv(synthetic().as_bytes()).unwrap();
+    assert_eq!((ostn.ncols, ostn.nrows), (4, 4));
+
+    let (lat, lon) = (52.658, 1.716);
+    let (x, y) = Ostn15::etrs89_projection().forward(lat, lon, true).unwrap();
+
+    let col = (x - 649000.) / 1000.;
+    let row = (y - 311000.) / 1000.;
+    let se = 102.5 + 0.25 * col + 0.125 * row;
+    let sn = -78.25 - 0.5 * row;
+
+    let (e, n) = ostn.etrs892osgb(lat, lon, true).unwrap();
+    assert!(maprs3d::is_close(e, x + se, 0., 1e-4));
+    assert!(maprs3d::is_close(n, y + sn, 0., 1e-4));
+
+    let (lat1, lon1) = ostn.osgb2etrs89(e, n, true).unwrap();
+    assert!(maprs3d::is_close(lat1, lat, 0., 1e-9));
+    assert!(maprs3d::is_close(lon1, lon, 0., 1e-9));
+
+    let h = maprs3d::ellipsoidal2orthometric(100., lat, lon, &ostn, true).unwrap();
+    assert!(maprs3d::is_close(h, 100. - (43.5 + 0.1 * col), 0., 1e-4));
+
+    let (se, sn, _) = ostn.shift(649500., 311500.).unwrap();
+    assert!(maprs3d::is_close(se, 102.5 + 0.125 + 0.0625, 0., 1e-6));
+    assert!(maprs3d::is_close(sn, -78.25 - 0.25, 0., 1e-6));
+
+    let (lat, lon) = Ostn15::etrs89_projection()
+        .inverse(649500., 311500., true)
+        .unwrap();
+    assert!(ostn.etrs892osgb(lat, lon, true).is_some());
+    assert!(maprs3d::Geoid::undulation(&ostn, lat, lon, true).is_none());
+
+    assert!(ostn.shift(648000., 312000.).is_none());
+    assert!(ostn.etrs892osgb(55., -3., true).is_none());
+
+    assert!(Ostn15::from_csv("1,0,0,1\n".as_bytes()).is_err());
+    assert!(Ostn15::from_csv("1,0,0,1,2,x,1\n".as_bytes()).is_err());
+    assert!(Ostn15::from_csv("1,0,0,1,2,3,1\n2,500,0,1,2,3,1\n".as_bytes()).is_err());
+}
+
+#[test]
+fn test_crs() {
+    let crs = NationalGrid::British.crs();
+    assert_eq!(crs.ellipsoid().model, "airy");
+    assert!(crs.towgs84.is_some());
+
+    let wgs84 = maprs3d::Crs::parse("+proj=utm +zone=31 +ellps=wgs84").unwrap();
+    let pipeline = maprs3d::Pipeline::new(crs, wgs84);
+
+    let (x, y) = pipeline.forward(651409.903, 313177.270).unwrap();
+    let (lat, lon) = pipeline.target.projection.inverse(x, y, true).unwrap();
+
+    let etrs_lat = 52. + 39. / 60. + 28.8282 / 3600.;
+    let etrs_lon = 1. + 42. / 60. + 57.8663 / 3600.;
+    assert!((lat - etrs_lat).abs() * 111e3 < 5.);
+    assert!((lon - etrs_lon).abs() * 68e3 < 5.);
+}
@@ -0,0 +1,75 @@
+#[test]
+fn test_velocity() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let pole = maprs3d::PlateModel::Itrf2014.pole("EURA").unwrap();
+
+    let (e, n, u) = maprs3d::plate_velocity_enu(49.1444, 12.8789, 666., &pole, &ell, true).unwrap();
+    assert!(maprs3d::is_close(e, 0.0203, 0., 5e-4));
+    assert!(maprs3d::is_close(n, 0.0154, 0., 5e-4));
+    assert!(u.abs() < 1e-4);
+
+    let pole = maprs3d::PlateModel::NnrMorvel56.pole("pacific").unwrap();
+    let (e, n, _) = maprs3d::plate_velocity_enu(19.8, -155.5, 0., &pole, &ell, true).unwrap();
+    assert!(maprs3d::is_close(e, -0.061, 0., 2e-3));
+    assert!(maprs3d::is_close(n, 0.032, 0., 2e-3));
+
+    let pole = maprs3d::PlateModel::NnrMorvel56.pole("SA").unwrap();
+    let (e, n, _) = maprs3d::plate_velocity_enu(-15.947, -47.878, 1106., &pole, &ell, true).unwrap();
+    assert!(maprs3d::is_close(e, -0.0032, 0., 5e-4));
+    assert!(maprs3d::is_close(n, 0.0101, 0., 5e-4));
+
+    let pole = maprs3d::EulerPole::from_pole(90., 0., 1., true);
+    let (vx, vy, vz) = maprs3d::plate_velocity(1e6, 0., 0., &pole);
+    assert_eq!((vx, vz), (0., 0.));
+    assert!(maprs3d::is_close(
+        vy,
+        1e6 * 1_f64.to_radians() / 1e6,
+        1e-12,
+        0.
+    ));
+}
+
+#[test]
+fn test_lookup() {
+    let model = maprs3d::PlateModel::get("NNR-MORVEL56").unwrap();
+    assert_eq!(model, maprs3d::PlateModel::NnrMorvel56);
+    assert_eq!(model.plates().len(), 25);
+    assert_eq!(model.pole("eu"), model.pole("Eurasia"));
+    assert!(model.pole("atlantis").is_none());
+
+    let model = maprs3d::PlateModel::get("itrf2014").unwrap();
+    assert_eq!(model.plates().len(), 11);
+    let pole = model.pole("noam").unwrap();
+    assert!(maprs3d::is_close(
+        pole.rate(),
+        0.697 * 4.8481368e-9,
+        1e-3,
+        0.
+    ));
+}
+
+#[test]
+fn test_propagate() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let pole = maprs3d::PlateModel::Itrf2014.pole("NOAM").unwrap();
+
+    let (x, y, z) = maprs3d::geodetic2ecef(39.0216, -76.827, 10., &ell, true).unwrap();
+    let (x1, y1, z1) = maprs3d::plate_propagate(x, y, z, &pole, 2010., 2020.);
+    let (x2, y2, z2) = maprs3d::plate_propagate(x1, y1, z1, &pole, 2020., 2010.);
+    assert!(maprs3d::is_close(x, x2, 0., 1e-6));
+    assert!(maprs3d::is_close(y, y2, 0., 1e-6));
+    assert!(maprs3d::is_close(z, z2, 0., 1e-6));
+
+    let shift = (x1 - x).hypot(y1 - y).hypot(z1 - z);
+    assert!(maprs3d::is_close(shift, 0.155, 0., 5e-3));
+
+    let lla =
+        maprs3d::plate_propagate_geodetic(39.0216, -76.827, 10., &pole, 2010., 2020., &ell, true)
+            .unwrap();
+    let enu =
+        maprs3d::geodetic2enu(lla.0, lla.1, lla.2, 39.0216, -76.827, 10., &ell, true).unwrap();
+    let (ve, vn, _) =
+        maprs3d::plate_velocity_enu(39.0216, -76.827, 10., &pole, &ell, true).unwrap();
+    assert!(maprs3d::is_close(enu.0, 10. * ve, 0., 1e-4));
+    assert!(maprs3d::is_close(enu.1, 10. * vn, 0., 1e-4));
+}
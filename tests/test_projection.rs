@@ -0,0 +1,250 @@
+use maprs3d::{
+    Crs, Helmert, LambertConformalConic, Mercator, Pipeline, Projection, TransverseMercator,
+};
+
+struct Numeric(TransverseMercator);
+
+impl Projection for Numeric {
+    fn ellipsoid(&self) -> &maprs3d::Ellipsoid {
+        &self.0.ellipsoid
+    }
+
+    fn forward(&self, lat: f64, lon: f64, deg: bool) -> Option<(f64, f64)> {
+        self.0.forward(lat, lon, deg)
+    }
+
+    fn inverse(&self, x: f64, y: f64, deg: bool) -> Option<(f64, f64)> {
+        self.0.inverse(x, y, deg)
+    }
+}
+
+#[test]
+fn test_parse() {
+    let crs =
+        Crs::parse("+proj=tmerc +lat_0=0 +lon_0=9 +k=0.9996 +x_0=500000 +ellps=wgs84").unwrap();
+    assert_eq!(crs.ellipsoid().model, "wgs84");
+    assert!(crs.towgs84.is_none());
+
+    let utm = maprs3d::utm_projection(32, true, &maprs3d::Ellipsoid::wgs84()).unwrap();
+    let (x, y) = crs.projection.forward(52., 10., true).unwrap();
+    let (xu, yu) = utm.forward(52., 10., true).unwrap();
+    assert!(maprs3d::is_close(x, xu, 0., 1e-9));
+    assert!(maprs3d::is_close(y, yu, 0., 1e-9));
+
+    let crs = Crs::parse("+proj=utm +zone=32 +ellps=WGS84 +units=m +no_defs").unwrap();
+    let (x1, y1) = crs.projection.forward(52., 10., true).unwrap();
+    assert!(maprs3d::is_close(x1, xu, 0., 1e-9));
+    assert!(maprs3d::is_close(y1, yu, 0., 1e-9));
+
+    let crs = Crs::parse("+proj=utm +zone=32 +datum=WGS84 +units=m +no_defs").unwrap();
+    assert_eq!(crs.ellipsoid().model, "wgs84");
+    assert!(crs.towgs84.is_some());
+    let (x2, y2) = crs.projection.forward(52., 10., true).unwrap();
+    assert!(maprs3d::is_close(x2, xu, 0., 1e-9));
+    assert!(maprs3d::is_close(y2, yu, 0., 1e-9));
+
+    let crs = Crs::parse("+proj=utm +zone=18 +datum=NAD83").unwrap();
+    assert_eq!(crs.ellipsoid().model, "grs80");
+
+    let osgb = Crs::parse(
+        "+proj=tmerc +lat_0=49 +lon_0=-2 +k=0.9996012717 +x_0=400000 +y_0=-100000 +datum=OSGB36",
+    )
+    .unwrap();
+    assert_eq!(osgb.ellipsoid().model, "airy");
+    assert_eq!(osgb.towgs84, Some(maprs3d::NationalGrid::British.towgs84()));
+
+    let crs = Crs::parse("+proj=utm +zone=31 +ellps=clrk80").unwrap();
+    assert_eq!(crs.ellipsoid().semimajor_axis, 6378249.145);
+
+    // PROJ builtins.gie
+    let crs = Crs::parse("+proj=lcc +ellps=GRS80 +lat_1=0.5 +lat_2=2").unwrap();
+    let (x, y) = crs.projection.forward(1., 2., true).unwrap();
+    assert!(maprs3d::is_close(x, 222588.439735968, 0., 1e-4));
+    assert!(maprs3d::is_close(y, 110660.533870800, 0., 1e-4));
+    let (x, y) = crs.projection.forward(-1., -2., true).unwrap();
+    assert!(maprs3d::is_close(x, -222756.879700279, 0., 1e-4));
+    assert!(maprs3d::is_close(y, -110532.797660827, 0., 1e-4));
+
+    let ell = maprs3d::Ellipsoid::clarke1866();
+    let cone = LambertConformalConic::new_2sp(18., 18., 0., -77., 0., 0., &ell, true).unwrap();
+    let (_, yc) = cone.forward(18., -77., true).unwrap();
+    assert!(yc > 1.9e6 && yc < 2.1e6);
+
+    for (k0, lat0) in [(1., 0.), (0.9996, 0.), (1., 18.)] {
+        let crs = Crs::parse(&format!(
+            "+proj=lcc +lat_1=18 +lat_0={} +lon_0=-77 +k_0={} +ellps=clrk66",
+            lat0, k0
+        ))
+        .unwrap();
+        let (x, y) = crs.projection.forward(18., -77., true).unwrap();
+        let expected = if lat0 == 0. { k0 * yc } else { 0. };
+        assert!(maprs3d::is_close(x, 0., 0., 1e-9));
+        assert!(maprs3d::is_close(y, expected, 0., 1e-6));
+    }
+
+    let web = Mercator::web();
+    let crs = Crs::parse("+proj=webmerc +lon_0=10 +x_0=1000 +y_0=2000").unwrap();
+    let (x, y) = crs.projection.forward(45., 12., true).unwrap();
+    let (xw, yw) = web.forward(45., 2., true).unwrap();
+    assert!(maprs3d::is_close(x, xw + 1000., 0., 1e-6));
+    assert!(maprs3d::is_close(y, yw + 2000., 0., 1e-6));
+    assert_eq!(crs.ellipsoid(), &web.ellipsoid);
+
+    let crs = Crs::parse("+proj=merc +R=6371000").unwrap();
+    assert_eq!(crs.ellipsoid().flattening, 0.);
+
+    assert!(Crs::parse("+proj=foo +ellps=wgs84").is_none());
+    assert!(Crs::parse("+proj=tmerc +ellps=nope").is_none());
+    assert!(Crs::parse("+proj=tmerc +lon_0=abc").is_none());
+    assert!(Crs::parse("+proj=tmerc +bogus=1").is_none());
+    assert!(Crs::parse("+proj=tmerc +units=ft").is_none());
+    assert!(Crs::parse("+proj=tmerc +lon_0=1 +lon_0=2").is_none());
+    assert!(Crs::parse("proj=tmerc").is_none());
+    assert!(Crs::parse("+proj=aea +lat_2=40").is_none());
+    assert!(Crs::parse("+proj=utm +zone=61").is_none());
+    assert!(Crs::parse("+proj=tmerc +towgs84=1,2").is_none());
+    assert!(Crs::parse("+proj=tmerc +datum=ED50").is_none());
+    assert!(Crs::parse("+proj=tmerc +datum=WGS84 +ellps=intl").is_none());
+}
+
+#[test]
+fn test_roundtrip() {
+    let definitions = [
+        "+proj=tmerc +lon_0=9 +k=0.9996 +x_0=500000",
+        "+proj=utm +zone=33 +south +ellps=intl",
+        "+proj=ups",
+        "+proj=stere +lat_0=-90 +lat_ts=-71 +lon_0=0",
+        "+proj=lcc +lat_1=49 +lat_2=44 +lat_0=46.5 +lon_0=3 +x_0=700000 +y_0=6600000",
+        "+proj=lcc +lat_1=18 +lon_0=-77 +k_0=1 +ellps=clrk66",
+        "+proj=aea +lat_1=29.5 +lat_2=45.5 +lat_0=23 +lon_0=-96",
+        "+proj=laea +lat_0=52 +lon_0=10 +x_0=4321000 +y_0=3210000",
+        "+proj=merc +lat_ts=30",
+        "+proj=webmerc",
+        "+proj=aeqd +lat_0=40 +lon_0=-100",
+        "+proj=gnom +lat_0=45 +lon_0=-60",
+        "+proj=omerc +lat_0=4 +lonc=115 +alpha=53.31582047 +gamma=53.13010235 +k=0.99984",
+        "+proj=eqc +lat_ts=30",
+        "+proj=cass +lat_0=50 +lon_0=-60",
+        "+proj=sinu +lon_0=-70",
+        "+proj=eqearth",
+        "+proj=robin",
+    ];
+
+    let points = [(-75., -50.), (-40., -62.), (40., -70.)];
+
+    for definition in definitions {
+        let crs = Crs::parse(definition).unwrap();
+        let (lat, lon) = if definition.contains("ups") {
+            (85., 20.)
+        } else if definition.contains("south") || definition.contains("-90") {
+            points[0]
+        } else if definition.contains("omerc") {
+            (5., 116.)
+        } else if definition.contains("tmerc") {
+            (50., 10.)
+        } else if definition.contains("cass") {
+            (45., -60.5)
+        } else if definition.contains("gnom") {
+            (40., -50.)
+        } else if definition.contains("utm") {
+            points[1]
+        } else {
+            points[2]
+        };
+
+        let (x, y) = crs.projection.forward(lat, lon, true).unwrap();
+        let (lat1, lon1) = crs.projection.inverse(x, y, true).unwrap();
+        assert!(maprs3d::is_close(lat1, lat, 0., 2e-8), "{}", definition);
+        assert!(maprs3d::is_close(lon1, lon, 0., 1e-8), "{}", definition);
+
+        assert!(crs.projection.scale(lat, lon, true).unwrap() > 0.);
+        assert!(crs.projection.convergence(lat, lon, true).is_some());
+    }
+}
+
+#[test]
+fn test_numeric() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let tmerc = TransverseMercator::new(0., 9., 0.9996, 500000., 0., &ell, true).unwrap();
+    let numeric = Numeric(tmerc.clone());
+
+    for &(lat, lon) in &[(52., 12.), (-30., 5.), (70., 15.)] {
+        let k = tmerc.scale(lat, lon, true).unwrap();
+        let gamma = tmerc.convergence(lat, lon, true).unwrap();
+
+        assert!(maprs3d::is_close(
+            numeric.scale(lat, lon, true).unwrap(),
+            k,
+            0.,
+            1e-9
+        ));
+        assert!(maprs3d::is_close(
+            numeric.convergence(lat, lon, true).unwrap(),
+            gamma,
+            0.,
+            1e-8
+        ));
+    }
+
+    assert!(numeric.scale(90., 0., true).is_none());
+    assert!(numeric.convergence(91., 0., true).is_none());
+}
+
+#[test]
+fn test_helmert() {
+    let helmert =
+        Helmert::from_towgs84(&[446.448, -125.157, 542.06, 0.15, 0.247, 0.842, -20.489]).unwrap();
+
+    let (x, y, z) = (3909833.018, -147097.1376, 5020322.2754);
+    let (x1, y1, z1) = helmert.forward(x, y, z);
+    let (x2, y2, z2) = helmert.inverse(x1, y1, z1);
+
+    assert!(maprs3d::is_close(x2, x, 0., 1e-8));
+    assert!(maprs3d::is_close(y2, y, 0., 1e-8));
+    assert!(maprs3d::is_close(z2, z, 0., 1e-8));
+
+    let shift = Helmert::translation(1., 2., 3.);
+    assert_eq!(shift.forward(0., 0., 0.), (1., 2., 3.));
+    assert!(Helmert::from_towgs84(&[1., 2.]).is_none());
+}
+
+#[test]
+fn test_pipeline() {
+    let pipeline = Pipeline::parse("+proj=utm +zone=32", "+proj=utm +zone=33").unwrap();
+
+    let utm32 = maprs3d::utm_projection(32, true, &maprs3d::Ellipsoid::grs80()).unwrap();
+    let utm33 = maprs3d::utm_projection(33, true, &maprs3d::Ellipsoid::grs80()).unwrap();
+
+    let (x, y) = utm32.forward(52., 12.5, true).unwrap();
+    let (x1, y1) = pipeline.forward(x, y).unwrap();
+    let (x2, y2) = utm33.forward(52., 12.5, true).unwrap();
+    assert!(maprs3d::is_close(x1, x2, 0., 1e-6));
+    assert!(maprs3d::is_close(y1, y2, 0., 1e-6));
+
+    let osgb = "+proj=tmerc +lat_0=49 +lon_0=-2 +k=0.9996012717 +x_0=400000 +y_0=-100000 \
+                +ellps=airy +towgs84=446.448,-125.157,542.06,0.15,0.247,0.842,-20.489";
+    let pipeline = Pipeline::parse(osgb, "+proj=utm +zone=30 +ellps=wgs84").unwrap();
+    let utm30 = maprs3d::utm_projection(30, true, &maprs3d::Ellipsoid::wgs84()).unwrap();
+
+    // OSTN15 test points TP01-TP03; the 7-parameter Helmert is good to about 5 m
+    for (lat, lon, e, n) in [
+        (49.92226393730, -6.29977752014, 91492.146, 11318.804),
+        (49.96006137820, -5.20304609998, 170370.718, 11572.405),
+        (50.43885825610, -4.10864563561, 250359.811, 62016.569),
+    ] {
+        let (x, y) = pipeline.forward(e, n).unwrap();
+        let (xu, yu) = utm30.forward(lat, lon, true).unwrap();
+        assert!((x - xu).hypot(y - yu) < 5.5, "{} {}", x - xu, y - yu);
+    }
+
+    let (e, n) = (530000., 180000.);
+    let (x, y) = pipeline.forward(e, n).unwrap();
+
+    let (lat_osgb, _) = pipeline.source.projection.inverse(e, n, true).unwrap();
+    let (lat_wgs, _) = pipeline.target.projection.inverse(x, y, true).unwrap();
+    assert!((lat_osgb - lat_wgs).abs() * 3600. > 1.);
+
+    let (e1, n1) = pipeline.inverse(x, y).unwrap();
+    assert!(maprs3d::is_close(e1, e, 0., 5e-3));
+    assert!(maprs3d::is_close(n1, n, 0., 5e-3));
+}
@@ -0,0 +1,187 @@
+use maprs3d::{geodetic2s2, s2_circle, s2_polygon, S2CellId};
+
+#[test]
+fn test_cell_id() {
+    let leaf = geodetic2s2(0., 0., 30, true).unwrap();
+    assert_eq!(leaf.id(), 0x1000000000000001);
+    assert_eq!(leaf.face(), 0);
+    assert_eq!(leaf.level(), 30);
+    assert!(leaf.is_leaf() && leaf.is_valid());
+
+    let tokens: Vec<String> = (0..6)
+        .map(|face| S2CellId::from_face(face).unwrap().to_token())
+        .collect();
+    assert_eq!(tokens, vec!["1", "3", "5", "7", "9", "b"]);
+
+    assert_eq!(geodetic2s2(90., 0., 0, true).unwrap().face(), 2);
+    assert_eq!(geodetic2s2(-90., 0., 0, true).unwrap().face(), 5);
+    assert_eq!(geodetic2s2(0., 180., 0, true).unwrap().face(), 3);
+
+    let cell = geodetic2s2(49.7035, 11.7707, 12, true).unwrap();
+    assert_eq!(cell.level(), 12);
+    assert_eq!(S2CellId::from_token(&cell.to_token()).unwrap(), cell);
+    assert_eq!(cell.to_string(), cell.to_token());
+
+    assert_eq!(S2CellId::from_id(leaf.id()), Some(leaf));
+    assert!(S2CellId::from_id(0).is_none());
+    assert!(S2CellId::from_id(0x2000000000000000).is_none());
+    assert!(S2CellId::from_id(0xd000000000000001).is_none());
+
+    assert_eq!(geodetic2s2(0., 45., 10, true).unwrap().face(), 0);
+    assert_eq!(geodetic2s2(0., 135., 10, true).unwrap().face(), 1);
+    assert_eq!(geodetic2s2(0., -135., 10, true).unwrap().face(), 4);
+
+    assert!(S2CellId::from_token("X").is_none());
+    assert!(S2CellId::from_token("d").is_none());
+    assert!(S2CellId::from_token("12345678901234567").is_none());
+    assert!(geodetic2s2(0., 0., 31, true).is_none());
+    assert!(geodetic2s2(91., 0., 10, true).is_none());
+}
+
+#[test]
+fn test_hierarchy() {
+    let leaf = geodetic2s2(-33.87, 151.21, 30, true).unwrap();
+
+    for level in 0..=30 {
+        let parent = leaf.parent(level).unwrap();
+        assert_eq!(parent.level(), level);
+        assert!(parent.contains(&leaf));
+        assert_eq!(parent, geodetic2s2(-33.87, 151.21, level, true).unwrap());
+    }
+
+    let cell = leaf.parent(10).unwrap();
+    assert!(cell.parent(11).is_none());
+
+    let children = cell.children().unwrap();
+    assert!(children
+        .iter()
+        .all(|c| c.level() == 11 && c.parent(10) == Some(cell)));
+    assert!(children.contains(&leaf.parent(11).unwrap()));
+    assert!(children
+        .windows(2)
+        .all(|w| w[0].range_max().id() + 2 == w[1].range_min().id()));
+    assert_eq!(children[0].range_min(), cell.range_min());
+    assert_eq!(children[3].range_max(), cell.range_max());
+
+    assert!(leaf.children().is_none());
+}
+
+#[test]
+fn test_geometry() {
+    let face = S2CellId::from_face(0).unwrap();
+    let (lat, lon) = face.centre(true);
+    assert!(maprs3d::is_close(lat, 0., 0., 1e-12));
+    assert!(maprs3d::is_close(lon, 0., 0., 1e-12));
+
+    let corner = (1f64 / 3f64.sqrt()).asin().to_degrees();
+    let vertices = face.vertices(true);
+    assert!(maprs3d::is_close(vertices[0].0, -corner, 0., 1e-12));
+    assert!(maprs3d::is_close(vertices[0].1, -45., 0., 1e-12));
+    assert!(maprs3d::is_close(vertices[2].0, corner, 0., 1e-12));
+    assert!(maprs3d::is_close(vertices[2].1, 45., 0., 1e-12));
+
+    for &(lat, lon) in &[(38.89, -77.03), (-33.87, 151.21), (89.9, 10.), (0., 180.)] {
+        for level in [1, 8, 20, 30] {
+            let cell = geodetic2s2(lat, lon, level, true).unwrap();
+            let (clat, clon) = cell.centre(true);
+            assert_eq!(geodetic2s2(clat, clon, level, true).unwrap(), cell);
+
+            let (rlat, rlon) = cell.centre(false);
+            assert!(maprs3d::is_close(rlat, clat.to_radians(), 0., 1e-12));
+            assert!(maprs3d::is_close(rlon, clon.to_radians(), 0., 1e-12));
+        }
+    }
+}
+
+#[test]
+fn test_neighbours() {
+    let faces: Vec<u8> = S2CellId::from_face(0)
+        .unwrap()
+        .edge_neighbours()
+        .iter()
+        .map(|c| c.face())
+        .collect();
+    assert_eq!(faces, vec![5, 1, 2, 4]);
+
+    let cell = geodetic2s2(51.5, -0.12, 14, true).unwrap();
+    let neighbours = cell.neighbours();
+    assert_eq!(neighbours.len(), 8);
+
+    for neighbour in cell.edge_neighbours() {
+        assert_eq!(neighbour.level(), 14);
+        assert!(neighbours.contains(&neighbour));
+        assert!(neighbour.edge_neighbours().contains(&cell));
+    }
+
+    let level = 3;
+    let mut cell = S2CellId::from_face(0)
+        .unwrap()
+        .range_min()
+        .parent(level)
+        .unwrap();
+
+    for _ in 0..(6 << (2 * level)) - 1 {
+        let id = cell.id();
+        let next = S2CellId::from_id(id + 2 * (id & id.wrapping_neg())).unwrap();
+        assert!(cell.edge_neighbours().contains(&next));
+        cell = next;
+    }
+
+    let corner = geodetic2s2(35.26, 45., 2, true).unwrap();
+    assert_eq!(corner.neighbours().len(), 7);
+}
+
+#[test]
+fn test_circle() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let (lat, lon, radius) = (48.8566, 2.3522, 5000.);
+
+    let cells = s2_circle(lat, lon, radius, 13, &ell, true).unwrap();
+    assert!(cells.windows(2).all(|w| w[0] < w[1]));
+    assert!(cells.contains(&geodetic2s2(lat, lon, 13, true).unwrap()));
+
+    for az in (0..360).step_by(15) {
+        let (plat, plon) =
+            maprs3d::vreckon(lat, lon, 0.99 * radius, az as f64, &ell, true).unwrap();
+        assert!(cells.contains(&geodetic2s2(plat, plon, 13, true).unwrap()));
+    }
+
+    for cell in &cells {
+        let (clat, clon) = cell.centre(true);
+        let (d, _) = maprs3d::vdist(lat, lon, clat, clon, &ell, true).unwrap();
+        assert!(d < radius + 1500.);
+    }
+
+    let single = s2_circle(lat, lon, 0., 10, &ell, true).unwrap();
+    assert_eq!(single, vec![geodetic2s2(lat, lon, 10, true).unwrap()]);
+
+    assert!(s2_circle(lat, lon, -1., 10, &ell, true).is_none());
+    assert!(s2_circle(lat, lon, 1e6, 30, &ell, true).is_none());
+}
+
+#[test]
+fn test_polygon() {
+    let polygon = [(10., 10.), (10., 10.5), (10.5, 10.5), (10.5, 10.)];
+
+    let cells = s2_polygon(&polygon, 10, true).unwrap();
+    assert!(cells.windows(2).all(|w| w[0] < w[1]));
+
+    for k in 0..=10 {
+        for m in 0..=10 {
+            let (lat, lon) = (10. + 0.05 * k as f64, 10. + 0.05 * m as f64);
+            assert!(cells.contains(&geodetic2s2(lat, lon, 10, true).unwrap()));
+        }
+    }
+
+    assert!(!cells.contains(&geodetic2s2(11., 11., 10, true).unwrap()));
+    assert!(!cells.contains(&geodetic2s2(10.25, 9.5, 10, true).unwrap()));
+
+    let reversed: Vec<(f64, f64)> = polygon.iter().rev().copied().collect();
+    assert_eq!(s2_polygon(&reversed, 10, true).unwrap(), cells);
+
+    let coarse = s2_polygon(&polygon, 3, true).unwrap();
+    assert!(cells.iter().all(|c| coarse.iter().any(|p| p.contains(c))));
+
+    assert!(s2_polygon(&[(0., 0.), (0., 1.)], 10, true).is_none());
+    assert!(s2_polygon(&[(0., 0.), (0., 120.), (0., 240.)], 10, true).is_none());
+}
@@ -0,0 +1,100 @@
+use maprs3d::{
+    tissot, AlbersEqualArea, Crs, EqualEarth, Equirectangular, Mercator, Sinusoidal,
+    TransverseMercator,
+};
+
+#[test]
+fn test_conformal() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let tmerc = TransverseMercator::new(0., 9., 0.9996, 500000., 0., &ell, true).unwrap();
+
+    for &(lat, lon) in &[(52., 12.), (-30., 5.), (70., 20.)] {
+        let t = tissot(&tmerc, lat, lon, true).unwrap();
+        let k = tmerc.scale(lat, lon, true).unwrap();
+
+        assert!(maprs3d::is_close(t.meridian_scale, k, 0., 1e-9));
+        assert!(maprs3d::is_close(t.parallel_scale, k, 0., 1e-9));
+        assert!(maprs3d::is_close(t.areal_scale, k * k, 0., 1e-9));
+        assert!(maprs3d::is_close(t.semimajor, k, 0., 1e-6));
+        assert!(maprs3d::is_close(t.semiminor, k, 0., 1e-6));
+        assert!(t.angular_distortion < 1e-4);
+        assert!(maprs3d::is_close(t.intersection, 90., 0., 1e-6));
+    }
+
+    let merc = Mercator::new(0., 1., 0., 0., &ell, true).unwrap();
+    let t = tissot(&merc, 60., 30., true).unwrap();
+    let k = merc.scale(60., 30., true).unwrap();
+    assert!(maprs3d::is_close(t.meridian_scale, k, 0., 1e-8));
+    assert!(maprs3d::is_close(t.areal_scale, k * k, 0., 1e-7));
+}
+
+#[test]
+fn test_equal_area() {
+    let ell = maprs3d::Ellipsoid::clarke1866();
+    let albers = AlbersEqualArea::new(29.5, 45.5, 23., -96., 0., 0., &ell, true).unwrap();
+
+    let t = tissot(&albers, 60., -80., true).unwrap();
+    let k = albers.scale(60., -80., true).unwrap();
+    assert!(maprs3d::is_close(t.parallel_scale, k, 0., 1e-9));
+    assert!(maprs3d::is_close(t.meridian_scale, 1. / k, 0., 1e-9));
+    assert!(maprs3d::is_close(t.areal_scale, 1., 0., 1e-9));
+    assert!(t.angular_distortion > 0.);
+    assert!(maprs3d::is_close(t.azimuth, 90., 0., 1e-6));
+
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let sinu = Sinusoidal::new(0., 0., 0., &ell, true).unwrap();
+    let ee = EqualEarth::new(0., 0., 0., &ell, true).unwrap();
+
+    for &(lat, lon) in &[(10., 30.), (-45., 120.), (70., -150.)] {
+        assert!(maprs3d::is_close(
+            tissot(&sinu, lat, lon, true).unwrap().areal_scale,
+            1.,
+            0.,
+            1e-8
+        ));
+        assert!(maprs3d::is_close(
+            tissot(&ee, lat, lon, true).unwrap().areal_scale,
+            1.,
+            0.,
+            1e-6
+        ));
+    }
+
+    let t = tissot(&sinu, 40., 0., true).unwrap();
+    assert!(maprs3d::is_close(t.meridian_scale, 1., 0., 1e-9));
+    assert!(maprs3d::is_close(t.parallel_scale, 1., 0., 1e-9));
+    assert!(t.angular_distortion < 1e-6);
+
+    let t = tissot(&sinu, 40., 60., true).unwrap();
+    assert!(t.intersection < 90.);
+    assert!(t.semimajor > 1. && t.semiminor < 1.);
+}
+
+#[test]
+fn test_equidistant() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let plate = Equirectangular::plate_carree(&ell);
+
+    let t = tissot(&plate, 60., 10., true).unwrap();
+    let expected = ell.semimajor_axis / maprs3d::parallel(60., &ell, true).unwrap();
+
+    assert!(maprs3d::is_close(t.meridian_scale, 1., 0., 1e-9));
+    assert!(maprs3d::is_close(t.parallel_scale, expected, 0., 1e-9));
+    assert!(maprs3d::is_close(t.semimajor, expected, 0., 1e-9));
+    assert!(maprs3d::is_close(t.semiminor, 1., 0., 1e-9));
+    assert!(maprs3d::is_close(t.azimuth, 90., 0., 1e-6));
+
+    let omega = 2. * ((expected - 1.) / (expected + 1.)).asin();
+    assert!(maprs3d::is_close(
+        t.angular_distortion,
+        omega.to_degrees(),
+        0.,
+        1e-6
+    ));
+
+    let crs = Crs::parse("+proj=eqc +ellps=wgs84").unwrap();
+    let t1 = tissot(crs.projection.as_ref(), 60., 10., true).unwrap();
+    assert!(maprs3d::is_close(t1.parallel_scale, expected, 0., 1e-9));
+
+    assert!(tissot(&plate, 90., 0., true).is_none());
+}
@@ -0,0 +1,95 @@
+use maprs3d::PolarStereographic;
+
+#[test]
+fn test_variant_a() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let stereo =
+        PolarStereographic::variant_a(true, 0., 0.994, 2000000., 2000000., &ell, true).unwrap();
+
+    let (x, y) = stereo.forward(90., 0., true).unwrap();
+    assert_eq!((x, y), (2000000., 2000000.));
+    assert_eq!(stereo.scale(90., 0., true), Some(0.994));
+
+    for &(lat, lon) in &[(85., 30.), (60., -120.), (0., 170.), (89.999, 0.5)] {
+        let (x, y) = stereo.forward(lat, lon, true).unwrap();
+        let (lat1, lon1) = stereo.inverse(x, y, true).unwrap();
+        assert!(maprs3d::is_close(lat1, lat, 0., 1e-10));
+        assert!(maprs3d::is_close(lon1, lon, 0., 1e-10));
+    }
+
+    let (x, y) = stereo.forward(85., 90., true).unwrap();
+    assert!(x > 2000000.);
+    assert!(maprs3d::is_close(y, 2000000., 0., 1e-6));
+    assert!(maprs3d::is_close(
+        stereo.convergence(85., 90., true).unwrap(),
+        90.,
+        0.,
+        1e-12
+    ));
+}
+
+#[test]
+fn test_variant_b() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let stereo = PolarStereographic::variant_b(-71., 70., 6000000., 6000000., &ell, true).unwrap();
+
+    assert!(!stereo.north);
+    assert!(maprs3d::is_close(stereo.k0, 0.972769012, 0., 1e-9));
+    assert!(maprs3d::is_close(
+        stereo.scale(-71., 0., true).unwrap(),
+        1.,
+        0.,
+        1e-12
+    ));
+
+    let (x, y) = stereo.forward(-75., 120., true).unwrap();
+    assert!(maprs3d::is_close(x, 7255380.79, 0., 1e-2));
+    assert!(maprs3d::is_close(y, 7053389.56, 0., 1e-2));
+
+    let (lat, lon) = stereo.inverse(x, y, true).unwrap();
+    assert!(maprs3d::is_close(lat, -75., 0., 1e-10));
+    assert!(maprs3d::is_close(lon, 120., 0., 1e-10));
+}
+
+#[test]
+fn test_variant_c() {
+    let ell = maprs3d::Ellipsoid::international1924();
+    let stereo = PolarStereographic::variant_c(-67., 140., 300000., 200000., &ell, true).unwrap();
+
+    let (x, y) = stereo.forward(-67., 140., true).unwrap();
+    assert!(maprs3d::is_close(x, 300000., 0., 1e-6));
+    assert!(maprs3d::is_close(y, 200000., 0., 1e-6));
+
+    let (x, y) = stereo.forward(-66.6052278, 140.0714, true).unwrap();
+    assert!(maprs3d::is_close(x, 303169.52, 0., 1e-2));
+    assert!(maprs3d::is_close(y, 244055.72, 0., 1e-2));
+
+    let (lat, lon) = stereo.inverse(x, y, true).unwrap();
+    assert!(maprs3d::is_close(lat, -66.6052278, 0., 1e-10));
+    assert!(maprs3d::is_close(lon, 140.0714, 0., 1e-10));
+}
+
+#[test]
+fn test_ups() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    assert!(maprs3d::geodetic2ups(83., 0., &ell, true).is_none());
+    assert!(maprs3d::geodetic2ups(-79., 0., &ell, true).is_none());
+
+    let ups = maprs3d::geodetic2ups(85., -30., &ell, true).unwrap();
+    assert_eq!(ups.band, 'Y');
+    assert!(ups.northern());
+    let (lat, lon) = maprs3d::ups2geodetic(true, ups.easting, ups.northing, &ell, true).unwrap();
+    assert!(maprs3d::is_close(lat, 85., 0., 1e-10));
+    assert!(maprs3d::is_close(lon, -30., 0., 1e-10));
+
+    let ups = maprs3d::geodetic2ups(-85., 30., &ell, true).unwrap();
+    assert_eq!(ups.band, 'B');
+    assert!(!ups.northern());
+    let (lat, lon) = maprs3d::ups2geodetic(false, ups.easting, ups.northing, &ell, true).unwrap();
+    assert!(maprs3d::is_close(lat, -85., 0., 1e-10));
+    assert!(maprs3d::is_close(lon, 30., 0., 1e-10));
+
+    let ups = maprs3d::geodetic2ups(-90., 0., &ell, true).unwrap();
+    assert_eq!((ups.easting, ups.northing), (2000000., 2000000.));
+}
@@ -0,0 +1,100 @@
+use maprs3d::TransverseMercator;
+
+#[test]
+fn test_tmerc_roundtrip() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let tmerc = TransverseMercator::new(0., 9., 0.9996, 500000., 0., &ell, true).unwrap();
+
+    for &(lat, lon) in &[
+        (0., 9.),
+        (45., 12.),
+        (-60., 2.),
+        (80., 40.),
+        (89.9, 95.),
+        (10., 50.),
+    ] {
+        let (x, y) = tmerc.forward(lat, lon, true).unwrap();
+        let (lat1, lon1) = tmerc.inverse(x, y, true).unwrap();
+        assert!(maprs3d::is_close(lat1, lat, 0., 1e-11));
+        assert!(maprs3d::is_close(lon1, lon, 0., 1e-11));
+    }
+
+    assert!(tmerc.forward(10., 120., true).is_none());
+    assert!(tmerc.forward(91., 9., true).is_none());
+}
+
+#[test]
+fn test_tmerc_scale() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let tmerc = TransverseMercator::new(0., 9., 0.9996, 500000., 0., &ell, true).unwrap();
+
+    assert!(maprs3d::is_close(
+        tmerc.scale(0., 9., true).unwrap(),
+        0.9996,
+        0.,
+        1e-15
+    ));
+    assert!(maprs3d::is_close(
+        tmerc.scale(50., 9., true).unwrap(),
+        0.9996,
+        0.,
+        1e-15
+    ));
+    assert!(maprs3d::is_close(
+        tmerc.convergence(50., 9., true).unwrap(),
+        0.,
+        0.,
+        1e-15
+    ));
+    assert!(tmerc.convergence(50., 12., true).unwrap() > 0.);
+
+    let (x, y) = tmerc.forward(0., 9., true).unwrap();
+    assert!(maprs3d::is_close(x, 500000., 0., 1e-9));
+    assert!(maprs3d::is_close(y, 0., 0., 1e-9));
+
+    let origin =
+        TransverseMercator::new(49., -2., 0.9996012717, 400000., -100000., &ell, true).unwrap();
+    let (x, y) = origin.forward(49., -2., true).unwrap();
+    assert!(maprs3d::is_close(x, 400000., 0., 1e-9));
+    assert!(maprs3d::is_close(y, -100000., 0., 1e-9));
+}
+
+#[test]
+fn test_utm() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    let utm = maprs3d::geodetic2utm(48.858093, 2.294694, &ell, true).unwrap();
+    assert_eq!((utm.zone, utm.band), (31, 'U'));
+    assert!(utm.northern());
+    assert!(maprs3d::is_close(utm.easting, 448265.915, 0., 1e-3));
+    assert!(maprs3d::is_close(utm.northing, 5411920.651, 0., 1e-3));
+
+    let (lat, lon) =
+        maprs3d::utm2geodetic(31, true, utm.easting, utm.northing, &ell, true).unwrap();
+    assert!(maprs3d::is_close(lat, 48.858093, 0., 1e-10));
+    assert!(maprs3d::is_close(lon, 2.294694, 0., 1e-10));
+
+    let utm = maprs3d::geodetic2utm(-33.857, 151.215, &ell, true).unwrap();
+    assert_eq!((utm.zone, utm.band), (56, 'H'));
+    assert!(!utm.northern());
+    let (lat, lon) =
+        maprs3d::utm2geodetic(56, false, utm.easting, utm.northing, &ell, true).unwrap();
+    assert!(maprs3d::is_close(lat, -33.857, 0., 1e-10));
+    assert!(maprs3d::is_close(lon, 151.215, 0., 1e-10));
+}
+
+#[test]
+fn test_utm_zone() {
+    assert_eq!(maprs3d::utm_zone(0., 0., true), Some((31, 'N')));
+    assert_eq!(maprs3d::utm_zone(-0.1, -0.1, true), Some((30, 'M')));
+    assert_eq!(maprs3d::utm_zone(60., 5., true), Some((32, 'V')));
+    assert_eq!(maprs3d::utm_zone(60., 2., true), Some((31, 'V')));
+    assert_eq!(maprs3d::utm_zone(78., 8., true), Some((31, 'X')));
+    assert_eq!(maprs3d::utm_zone(78., 10., true), Some((33, 'X')));
+    assert_eq!(maprs3d::utm_zone(78., 25., true), Some((35, 'X')));
+    assert_eq!(maprs3d::utm_zone(78., 40., true), Some((37, 'X')));
+    assert_eq!(maprs3d::utm_zone(84., 180., true), Some((60, 'X')));
+    assert_eq!(maprs3d::utm_zone(10., -180., true), Some((1, 'P')));
+    assert!(maprs3d::utm_zone(84.1, 0., true).is_none());
+    assert!(maprs3d::utm_zone(-80.1, 0., true).is_none());
+}
@@ -13,4 +13,16 @@ fn test_track2() {
         assert!(maprs3d::is_close(lla.0, lla2.0, 1e-6, 1e-12));
         assert!(maprs3d::is_close(lla.1, lla2.1, 1e-6, 1e-12));
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_track2_npts() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    for npts in [1, 2, 3, 5, 10] {
+        let llas = maprs3d::track2(40., 80., 65., -148., &ell, true, npts).unwrap();
+        assert_eq!(llas.len(), npts);
+    }
+
+    assert!(maprs3d::track2(40., 80., 65., -148., &ell, true, 0).is_none());
+}
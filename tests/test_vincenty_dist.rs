@@ -42,3 +42,34 @@ fn test_identity() {
         assert!(maprs3d::is_close(az1, az, 1e-4, 1e-12)); // TODO
     }
 }
+
+#[test]
+fn test_antimeridian() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+
+    let (dist, az) = maprs3d::vdist(0., 179., 0., -179., &ell, true).unwrap();
+    assert!(maprs3d::is_close(dist, 222638.98, 0., 0.01));
+    assert!(maprs3d::is_close(az, 90., 0., 1e-9));
+
+    let (dist, az) = maprs3d::vdist(0., -179., 0., 179., &ell, true).unwrap();
+    assert!(maprs3d::is_close(dist, 222638.98, 0., 0.01));
+    assert!(maprs3d::is_close(az, 270., 0., 1e-9));
+}
+
+#[test]
+fn test_unequal_latitudes() {
+    let ell = maprs3d::Ellipsoid::grs80();
+
+    // Flinders Peak to Buninyong
+    let (dist, az) = maprs3d::vdist(
+        -37.95103342,
+        144.42486789,
+        -37.65282114,
+        143.92649554,
+        &ell,
+        true,
+    )
+    .unwrap();
+    assert!(maprs3d::is_close(dist, 54972.271, 0., 1e-3));
+    assert!(maprs3d::is_close(az, 306.86816, 0., 1e-5));
+}
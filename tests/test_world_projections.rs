@@ -0,0 +1,193 @@
+use maprs3d::{CassiniSoldner, EqualEarth, Equirectangular, Robinson, Sinusoidal};
+
+fn q(lat: f64, ell: &maprs3d::Ellipsoid) -> f64 {
+    let e = ell.eccentricity;
+    let s = lat.to_radians().sin();
+
+    (1. - e * e) * (s / (1. - (e * s).powi(2)) - ((1. - e * s) / (1. + e * s)).ln() / (2. * e))
+}
+
+fn ellipsoidal_area(lat1: f64, lat2: f64, dlon: f64, ell: &maprs3d::Ellipsoid) -> f64 {
+    ell.semimajor_axis.powi(2) * dlon.to_radians() * (q(lat2, ell) - q(lat1, ell)) / 2.
+}
+
+fn projected_area<F>(lat1: f64, lat2: f64, lon1: f64, lon2: f64, forward: F) -> f64
+where
+    F: Fn(f64, f64) -> (f64, f64),
+{
+    let steps = 2000;
+    let mut ring = Vec::new();
+
+    for i in 0..steps {
+        let t = i as f64 / steps as f64;
+        ring.push(forward(lat1, lon1 + (lon2 - lon1) * t));
+    }
+    for i in 0..steps {
+        let t = i as f64 / steps as f64;
+        ring.push(forward(lat1 + (lat2 - lat1) * t, lon2));
+    }
+    for i in 0..steps {
+        let t = i as f64 / steps as f64;
+        ring.push(forward(lat2, lon2 - (lon2 - lon1) * t));
+    }
+    for i in 0..steps {
+        let t = i as f64 / steps as f64;
+        ring.push(forward(lat2 - (lat2 - lat1) * t, lon1));
+    }
+
+    let mut area = 0.;
+    for i in 0..ring.len() {
+        let (x1, y1) = ring[i];
+        let (x2, y2) = ring[(i + 1) % ring.len()];
+        area += x1 * y2 - x2 * y1;
+    }
+
+    area.abs() / 2.
+}
+
+#[test]
+fn test_equirectangular() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let plate = Equirectangular::plate_carree(&ell);
+
+    let (x, y) = plate.forward(0., 90., true).unwrap();
+    assert!(maprs3d::is_close(
+        x,
+        ell.semimajor_axis * 90f64.to_radians(),
+        0.,
+        1e-6
+    ));
+    assert!(maprs3d::is_close(y, 0., 0., 1e-9));
+
+    let (_, y) = plate.forward(90., 0., true).unwrap();
+    assert!(maprs3d::is_close(
+        y,
+        maprs3d::rsphere::rectifying(&ell) * 90f64.to_radians(),
+        0.,
+        1e-3
+    ));
+
+    let eqc = Equirectangular::new(30., 10., 1000., 2000., &ell, true).unwrap();
+    for &(lat, lon) in &[(45., 170.), (-60., -100.), (0., 10.)] {
+        let (x, y) = eqc.forward(lat, lon, true).unwrap();
+        let (lat1, lon1) = eqc.inverse(x, y, true).unwrap();
+        assert!(maprs3d::is_close(lat1, lat, 0., 1e-9));
+        assert!(maprs3d::is_close(lon1, lon, 0., 1e-10));
+    }
+
+    assert!(Equirectangular::new(90., 0., 0., 0., &ell, true).is_none());
+}
+
+#[test]
+fn test_cassini() {
+    let a = 31706587.88;
+    let f = 1. / 294.2606764;
+    let ell = maprs3d::Ellipsoid::new(a, a * (1. - f), "Clarke 1858", "clarke1858");
+
+    let cass = CassiniSoldner::new(
+        10. + 26. / 60. + 30. / 3600.,
+        -(61. + 20. / 60.),
+        430000.,
+        325000.,
+        &ell,
+        true,
+    )
+    .unwrap();
+
+    let (x, y) = cass.forward(10., -62., true).unwrap();
+    assert!(maprs3d::is_close(x, 66644.94, 0., 1e-2));
+    assert!(maprs3d::is_close(y, 82536.22, 0., 1e-2));
+
+    let (lat, lon) = cass.inverse(x, y, true).unwrap();
+    assert!(maprs3d::is_close(lat, 10., 0., 1e-9));
+    assert!(maprs3d::is_close(lon, -62., 0., 1e-9));
+
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let cass = CassiniSoldner::new(0., 20., 0., 0., &ell, true).unwrap();
+
+    let (x, y) = cass.forward(50., 20., true).unwrap();
+    assert!(maprs3d::is_close(x, 0., 0., 1e-9));
+    assert!(maprs3d::is_close(
+        y,
+        maprs3d::lox::meridian_arc(0., 50., &ell, true).unwrap(),
+        0.,
+        1e-6
+    ));
+}
+
+#[test]
+fn test_sinusoidal() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let sinu = Sinusoidal::new(-30., 0., 0., &ell, true).unwrap();
+
+    for &(lat, lon) in &[(45., 140.), (-60., -100.), (0., -30.), (89., 10.)] {
+        let (x, y) = sinu.forward(lat, lon, true).unwrap();
+        let (lat1, lon1) = sinu.inverse(x, y, true).unwrap();
+        assert!(maprs3d::is_close(lat1, lat, 0., 1e-9));
+        assert!(maprs3d::is_close(lon1, lon, 0., 1e-8));
+    }
+
+    let area = projected_area(20., 40., -10., 15., |lat, lon| {
+        sinu.forward(lat, lon, true).unwrap()
+    });
+    let expected = ellipsoidal_area(20., 40., 25., &ell);
+    assert!(maprs3d::is_close(area, expected, 1e-6, 0.));
+
+    let (x, _) = sinu.forward(0., 150., true).unwrap();
+    assert!(sinu.inverse(x * 1.1, 0., true).is_none());
+}
+
+#[test]
+fn test_equal_earth() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let ee = EqualEarth::new(0., 0., 0., &ell, true).unwrap();
+
+    for &(lat, lon) in &[(45., 90.), (-89., -179.), (10., 0.), (70., 35.)] {
+        let (x, y) = ee.forward(lat, lon, true).unwrap();
+        let (lat1, lon1) = ee.inverse(x, y, true).unwrap();
+        assert!(maprs3d::is_close(lat1, lat, 0., 2e-8));
+        assert!(maprs3d::is_close(lon1, lon, 0., 1e-10));
+    }
+
+    let area = projected_area(-30., 60., 100., 130., |lat, lon| {
+        ee.forward(lat, lon, true).unwrap()
+    });
+    let expected = ellipsoidal_area(-30., 60., 30., &ell);
+    assert!(maprs3d::is_close(area, expected, 1e-6, 0.));
+
+    assert!(ee.inverse(0., 1e8, true).is_none());
+}
+
+#[test]
+fn test_robinson() {
+    let ell = maprs3d::Ellipsoid::wgs84();
+    let a = ell.semimajor_axis;
+    let robin = Robinson::new(0., 0., 0., &ell, true).unwrap();
+
+    let (x, y) = robin.forward(45., 90., true).unwrap();
+    assert!(maprs3d::is_close(
+        x,
+        0.8487 * a * 0.8962 * 90f64.to_radians(),
+        0.,
+        1e-6
+    ));
+    assert!(maprs3d::is_close(y, 1.3523 * a * 0.5571, 0., 1e-6));
+
+    let (x, y) = robin.forward(-90., 180., true).unwrap();
+    assert!(maprs3d::is_close(
+        x.abs(),
+        0.8487 * a * 0.5322 * std::f64::consts::PI,
+        0.,
+        1e-6
+    ));
+    assert!(maprs3d::is_close(y, -1.3523 * a, 0., 1e-6));
+
+    for &(lat, lon) in &[(47.3, 10.), (-33.3, -70.), (0., 179.), (82.5, -45.)] {
+        let (x, y) = robin.forward(lat, lon, true).unwrap();
+        let (lat1, lon1) = robin.inverse(x, y, true).unwrap();
+        assert!(maprs3d::is_close(lat1, lat, 0., 1e-9));
+        assert!(maprs3d::is_close(lon1, lon, 0., 1e-9));
+    }
+
+    assert!(robin.forward(91., 0., true).is_none());
+}